@@ -117,6 +117,26 @@ pub trait Group: PartialEq + Sized {
         let a_bc = self.combine(&b.combine(c));
         ab_c == a_bc
     }
+
+    /// `self` raised to the `n`-th power via square-and-multiply. Negative `n` first inverts
+    /// the base, `n == 0` returns the identity.
+    fn pow(&self, n: i64) -> Self {
+        let mut base = if n < 0 {
+            self.inv()
+        } else {
+            self.combine(&Self::identity())
+        };
+        let mut exp = n.unsigned_abs();
+        let mut result = Self::identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.combine(&base);
+            }
+            base = base.combine(&base);
+            exp >>= 1;
+        }
+        result
+    }
 }
 
 /// Implement Group for Wrapper types containing a group as element
@@ -278,5 +298,134 @@ impl_is_positive! { i64 }
 impl_is_positive! { f32 }
 impl_is_positive! { f64 }
 
-// TODO: add bigdecimal support
-// TODO: add 'complex number' support
+/// Numeric backend for `num_complex::Complex<f64>`, so Moebius transformations can act on
+/// genuinely complex points rather than only real boundary points.
+#[cfg(feature = "complex")]
+mod complex_numeric {
+    use super::{AddIdentity, Inverse, MulIdentity, NumericAddIdentity, NumericMulIdentity, Signed, SquareRoot};
+    use num_complex::Complex;
+
+    impl AddIdentity for Complex<f64> {
+        fn zero() -> Self {
+            Complex::new(0.0, 0.0)
+        }
+    }
+
+    impl NumericAddIdentity for Complex<f64> {
+        fn is_zero(&self, threshold: Option<f64>) -> bool {
+            match threshold {
+                Some(tol) => self.norm() <= tol,
+                None => *self == Self::zero(),
+            }
+        }
+    }
+
+    impl MulIdentity for Complex<f64> {
+        fn one() -> Self {
+            Complex::new(1.0, 0.0)
+        }
+    }
+
+    impl NumericMulIdentity for Complex<f64> {
+        fn is_one(&self, threshold: Option<f64>) -> bool {
+            (*self - Self::one()).is_zero(threshold)
+        }
+    }
+
+    impl SquareRoot for Complex<f64> {
+        fn square_root(&self) -> Self {
+            self.sqrt()
+        }
+    }
+
+    impl Signed for Complex<f64> {
+        fn signed(&self) -> Self {
+            if self.norm() == 0.0 {
+                Self::zero()
+            } else {
+                *self / self.norm()
+            }
+        }
+    }
+
+    impl Inverse for Complex<f64> {
+        type Error = &'static str;
+
+        fn inverse(&self) -> std::result::Result<Self, Self::Error> {
+            if self.norm() == 0.0 {
+                return Err("Complex number is not invertible: zero has no multiplicative inverse");
+            }
+            Ok(Self::one() / *self)
+        }
+    }
+}
+
+/// Exact numeric backend for `num_rational::BigRational`, so that e.g. `SpecialLinear`'s
+/// `determinant() == one.determinant()` restriction and the modular group `PSL(2,Z)` can be
+/// checked with exact equality instead of a floating threshold.
+#[cfg(feature = "exact")]
+mod exact_numeric {
+    use super::{
+        AddIdentity, Inverse, IsPositive, MulIdentity, NumericAddIdentity, NumericMulIdentity,
+        Signed, SquareRoot,
+    };
+    use num_rational::BigRational;
+    use num_traits::{One, Signed as _, ToPrimitive, Zero};
+
+    impl AddIdentity for BigRational {
+        fn zero() -> Self {
+            <BigRational as Zero>::zero()
+        }
+    }
+
+    impl NumericAddIdentity for BigRational {
+        /// Exact arithmetic: the threshold is ignored, `BigRational` equality is always exact.
+        fn is_zero(&self, _threshold: Option<f64>) -> bool {
+            <BigRational as Zero>::is_zero(self)
+        }
+    }
+
+    impl MulIdentity for BigRational {
+        fn one() -> Self {
+            <BigRational as One>::one()
+        }
+    }
+
+    impl NumericMulIdentity for BigRational {
+        fn is_one(&self, _threshold: Option<f64>) -> bool {
+            self == &Self::one()
+        }
+    }
+
+    impl SquareRoot for BigRational {
+        /// `BigRational` is not closed under square roots; this returns the best rational
+        /// approximation obtained by taking the `f64` square root and converting back.
+        fn square_root(&self) -> Self {
+            let approx = self.to_f64().unwrap_or(0.0).abs().sqrt();
+            BigRational::from_float(approx).unwrap_or_else(BigRational::zero)
+        }
+    }
+
+    impl Signed for BigRational {
+        fn signed(&self) -> Self {
+            self.signum()
+        }
+    }
+
+    impl IsPositive for BigRational {
+        fn is_positive(&self) -> bool {
+            self > &BigRational::zero()
+        }
+    }
+
+    impl Inverse for BigRational {
+        type Error = &'static str;
+
+        fn inverse(&self) -> std::result::Result<Self, Self::Error> {
+            if self.is_zero(None) {
+                return Err("Rational number is not invertible: zero has no multiplicative inverse");
+            }
+            Ok(self.recip())
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! The Poincaré disc model, gated behind the `complex` feature: `to_disc_model` conjugates an
+//! upper-half-plane isometry by the Cayley transform, which requires `Numeric`/`NumericAddIdentity`
+//! impls for `Complex<f64>` that only exist when `complex` is enabled (see
+//! `algebraic_extensions::complex_numeric`).
+#![cfg(feature = "complex")]
+
+use crate::fuchsian_group::SpecialLinearMoebiusTransformation;
+use crate::group_action::Action;
+use crate::moebius::MoebiusTransformation;
+use num_complex::Complex;
+
+/// The [Cayley transform](https://en.wikipedia.org/wiki/Cayley_transform) `C(z) = (z − i)/(z + i)`,
+/// as the Moebius transformation with matrix `[[1, −i], [1, i]]`, conjugating the upper half
+/// plane onto the unit disc.
+fn cayley_transform() -> MoebiusTransformation<Complex<f64>> {
+    MoebiusTransformation::new(
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, -1.0),
+        Complex::new(1.0, 0.0),
+        Complex::new(0.0, 1.0),
+    )
+}
+
+/// A unit-circle-preserving Moebius transformation, i.e. an automorphism of the
+/// [Poincaré disc](https://en.wikipedia.org/wiki/Poincar%C3%A9_disk_model): the signature-(1,1)
+/// unitary form `[[α, β], [β̄, ᾱ]]` with `|α|² − |β|² = 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscAutomorphism {
+    alpha: Complex<f64>,
+    beta: Complex<f64>,
+}
+
+impl DiscAutomorphism {
+    pub fn new(alpha: Complex<f64>, beta: Complex<f64>) -> Self {
+        Self { alpha, beta }
+    }
+
+    pub fn alpha(&self) -> Complex<f64> {
+        self.alpha
+    }
+
+    pub fn beta(&self) -> Complex<f64> {
+        self.beta
+    }
+}
+
+impl Action<Complex<f64>> for DiscAutomorphism {
+    fn map(&self, x: &Complex<f64>) -> Complex<f64> {
+        let nom = self.alpha * *x + self.beta;
+        let denom = self.beta.conj() * *x + self.alpha.conj();
+        nom / denom
+    }
+}
+
+impl SpecialLinearMoebiusTransformation<f64> {
+    /// Conjugates this upper-half-plane isometry by the Cayley transform
+    /// (`C · M · C⁻¹`), yielding the corresponding automorphism of the unit disc.
+    pub fn to_disc_model(&self) -> DiscAutomorphism {
+        let c = cayley_transform();
+        let c_inv = c
+            .inverse(None)
+            .expect("the Cayley transform is always invertible");
+        let m_complex = MoebiusTransformation::new(
+            Complex::new(self.a, 0.0),
+            Complex::new(self.b, 0.0),
+            Complex::new(self.c, 0.0),
+            Complex::new(self.d, 0.0),
+        );
+        let conjugated = c * m_complex * c_inv;
+        DiscAutomorphism::new(conjugated.a, conjugated.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_disc_automorphism_preserves_unit_circle() {
+        let identity =
+            SpecialLinearMoebiusTransformation::try_from(MoebiusTransformation::new(1.0, 0.0, 0.0, 1.0), None)
+                .unwrap();
+        let disc = identity.to_disc_model();
+
+        assert_abs_diff_eq!(disc.alpha().norm(), 1.0, epsilon = 1e-9);
+        assert_abs_diff_eq!(disc.beta().norm(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_disc_automorphism_maps_into_unit_disc() {
+        // a rotation of the upper half plane fixing i
+        let rotation =
+            SpecialLinearMoebiusTransformation::try_from(MoebiusTransformation::new(0.0, -1.0, 1.0, 0.0), None)
+                .unwrap();
+        let disc = rotation.to_disc_model();
+
+        let origin = Complex::new(0.0, 0.0);
+        let image = disc.map(&origin);
+        assert!(image.norm() < 1.0 + 1e-9);
+    }
+}
@@ -2,9 +2,11 @@ use crate::{
     algebraic_extensions::{
         Group, IsPositive, MulIdentity, Numeric, NumericAddIdentity, SquareRoot,
     },
-    group_action::{Action, FinitelyGeneratedGroup, SpecialLinear},
+    geometry::boundary::BoundaryPoint,
+    group_action::{Action, CanonicalKey, FinitelyGeneratedGroup, SpecialLinear},
     moebius::MoebiusTransformation,
     set_extensions::{SetRestriction, Wrapper},
+    NUMERIC_THRESHOLD,
 };
 use num_complex::Complex;
 use std::ops::{Add, Deref, Div, Mul, Neg};
@@ -76,6 +78,115 @@ impl<T> SpecialLinearMoebiusTransformation<T> {
     }
 }
 
+/// The geometric type of an isometry of the hyperbolic plane, determined by the trace
+/// `t = a + d` of its determinant-1 matrix representative: `|t| < 2` is a rotation around a
+/// single fixed point in the open upper half-plane (`Elliptic`), `|t| == 2` has a single fixed
+/// point on the boundary (`Parabolic`), and `|t| > 2` has two distinct boundary fixed points,
+/// the endpoints of the invariant axis, one attracting and one repelling under iteration
+/// (`Hyperbolic`).
+pub enum Classification<T> {
+    Elliptic { fixed: Complex<T> },
+    Parabolic { fixed: BoundaryPoint<T> },
+    Hyperbolic {
+        attracting: BoundaryPoint<T>,
+        repelling: BoundaryPoint<T>,
+    },
+}
+
+impl<T> SpecialLinearMoebiusTransformation<T> {
+    /// The multiplier (derivative) `1 / (c·z + d)²` of the map at a finite fixed point `z`;
+    /// whichever of the two fixed points has multiplier magnitude `< 1` is the attracting one.
+    fn multiplier(&self, z: T) -> T
+    where
+        T: Numeric + MulIdentity + Copy + Div<Output = T>,
+    {
+        let denom = self.c * z + self.d;
+        T::one() / (denom * denom)
+    }
+
+    /// Classifies the isometry by its trace and, in the non-elliptic cases, returns its fixed
+    /// point(s) by solving `c·z² + (d − a)·z − b = 0`: a single fixed point on the boundary for
+    /// `Parabolic`, two boundary points (attracting/repelling) for `Hyperbolic`, and a single
+    /// point in the open upper half-plane for `Elliptic`.
+    pub fn classify(&self, numeric_threshold: Option<f64>) -> Classification<T>
+    where
+        T: Numeric
+            + NumericAddIdentity
+            + MulIdentity
+            + SquareRoot
+            + IsPositive
+            + Copy
+            + PartialOrd
+            + Div<Output = T>,
+    {
+        let trace = self.a + self.d;
+        let two = T::one() + T::one();
+        let four = two * two;
+        let discriminant = trace * trace + (-four);
+
+        if self.c.is_zero(numeric_threshold) {
+            // `a·d == 1`, so `|trace| >= 2` always: this case is never elliptic. One fixed
+            // point is always infinity; the other solves (d − a)·z = b, which degenerates to
+            // infinity too (a double root, i.e. Parabolic) exactly when `d == a`.
+            let denom = self.d + (-self.a);
+            return if denom.is_zero(numeric_threshold) {
+                Classification::Parabolic {
+                    fixed: BoundaryPoint::Infinity,
+                }
+            } else {
+                let z = self.b / denom;
+                if self.multiplier(z) < T::one() {
+                    Classification::Hyperbolic {
+                        attracting: BoundaryPoint::Regular(z),
+                        repelling: BoundaryPoint::Infinity,
+                    }
+                } else {
+                    Classification::Hyperbolic {
+                        attracting: BoundaryPoint::Infinity,
+                        repelling: BoundaryPoint::Regular(z),
+                    }
+                }
+            };
+        }
+
+        if discriminant.is_zero(numeric_threshold) {
+            let fixed = BoundaryPoint::Regular((self.a + (-self.d)) / (self.c + self.c));
+            Classification::Parabolic { fixed }
+        } else if four > trace * trace {
+            let sqrt_disc = (four + (-(trace * trace))).square_root();
+            let two_c = self.c + self.c;
+            let real_part = (self.a + (-self.d)) / two_c;
+            let im_part = sqrt_disc / two_c;
+            let im_part = if im_part.is_positive() {
+                im_part
+            } else {
+                -im_part
+            };
+            Classification::Elliptic {
+                fixed: Complex::new(real_part, im_part),
+            }
+        } else {
+            let sqrt_disc = discriminant.square_root();
+            let base = self.a + (-self.d);
+            let two_c = self.c + self.c;
+            let p = (base + sqrt_disc) / two_c;
+            let q = (base + (-sqrt_disc)) / two_c;
+
+            if self.multiplier(p) < T::one() {
+                Classification::Hyperbolic {
+                    attracting: BoundaryPoint::Regular(p),
+                    repelling: BoundaryPoint::Regular(q),
+                }
+            } else {
+                Classification::Hyperbolic {
+                    attracting: BoundaryPoint::Regular(q),
+                    repelling: BoundaryPoint::Regular(p),
+                }
+            }
+        }
+    }
+}
+
 impl<T> Deref for SpecialLinearMoebiusTransformation<T> {
     type Target = MoebiusTransformation<T>;
     fn deref(&self) -> &Self::Target {
@@ -153,8 +264,9 @@ where
     MoebiusTransformation<T>: SpecialLinear<T>,
 {
     generators: Vec<MoebiusTransformation<T>>,
-    // TODO: maybe add...
-    // inverse_generator: Vec<SpecialLinear<T>>,
+    /// The inverse of each of `generators`, at the same index, precomputed once at construction
+    /// so that orbit enumeration can walk the full generating set without recomputing them.
+    inverse_generators: Vec<MoebiusTransformation<T>>,
 }
 
 impl<T> FinitelyGeneratedGroup for FuchsianGroup<T>
@@ -172,25 +284,42 @@ impl<T> FuchsianGroup<T>
 where
     MoebiusTransformation<T>: SpecialLinear<T>,
 {
-    pub fn try_push(&mut self, m: MoebiusTransformation<T>) -> bool {
+    pub fn inverse_generators(&self) -> &[MoebiusTransformation<T>] {
+        &self.inverse_generators
+    }
+
+    pub fn try_push(&mut self, m: MoebiusTransformation<T>) -> bool
+    where
+        MoebiusTransformation<T>: Group,
+    {
         if let Some(slm) = MoebiusTransformation::try_new(m) {
-            self.generators.push(slm)
+            self.inverse_generators.push(slm.inv());
+            self.generators.push(slm);
+            return true;
         }
         false
     }
 
     /// Tries to create a `ProjectedMoebiusTransformation<T>` for each 'raw generator'
     /// of type `MoebiusTransformations<T>` satisfying `determinant == 1`.
-    pub fn create_from_valid(raw_generators: Vec<MoebiusTransformation<T>>) -> Self {
+    pub fn create_from_valid(raw_generators: Vec<MoebiusTransformation<T>>) -> Self
+    where
+        MoebiusTransformation<T>: Group,
+    {
         let mut generators = Vec::new();
+        let mut inverse_generators = Vec::new();
 
         for m in raw_generators.into_iter() {
             if let Some(slm) = MoebiusTransformation::try_new(m) {
-                generators.push(slm)
+                inverse_generators.push(slm.inv());
+                generators.push(slm);
             }
         }
 
-        Self { generators }
+        Self {
+            generators,
+            inverse_generators,
+        }
     }
 
     /// Tries to create a `ProjectedMoebiusTransformation<T>` for each 'raw generator',
@@ -199,6 +328,12 @@ where
     /// - `[ -1, 0; 0, 1 ]` has determinant `-1` and is not orientation-preserving
     /// - `[ -1, 1; 0, 0 ]` has determinant `0` and is not invertible
     /// - `[ 2, 1; 1, 1 ]` and `[ 4, 2; 2, 2 ]` are projected to the same element and will result in only one generator
+    ///
+    /// Generators are canonicalized before deduplication: each is rescaled to determinant `1`,
+    /// then sign-normalized so that the first nonzero entry among `a, c` is positive (a matrix
+    /// and its negation represent the same element of PSL(2,R)). Canonicalized generators are
+    /// deduplicated via their (threshold-quantized) `canonical_key`, so e.g. `[2,1;1,1]` and
+    /// `[4,2;2,2]` collapse to a single generator.
     pub fn create_projected(
         raw_generators: Vec<MoebiusTransformation<T>>,
         numeric_threshold: Option<f64>,
@@ -212,17 +347,46 @@ where
             + IsPositive
             + Copy
             + PartialEq,
-        MoebiusTransformation<T>: PartialEq,
+        MoebiusTransformation<T>: PartialEq + Group + CanonicalKey,
     {
-        // TODO: filter out duplicates
-        let generators = raw_generators
-            .into_iter()
-            .flat_map(|m| {
-                SpecialLinearMoebiusTransformation::<T>::try_from(m, numeric_threshold)
-                    .map(|slm| slm.m)
-            })
-            .collect::<Vec<MoebiusTransformation<T>>>();
-        Self { generators }
+        let mut seen = std::collections::HashSet::new();
+        let mut generators = Vec::new();
+        let mut inverse_generators = Vec::new();
+
+        for m in raw_generators.into_iter() {
+            if let Some(slm) = SpecialLinearMoebiusTransformation::<T>::try_from(m, numeric_threshold) {
+                let canonical = canonicalize_sign(slm.m, numeric_threshold);
+                if seen.insert(canonical.canonical_key(numeric_threshold)) {
+                    inverse_generators.push(canonical.inv());
+                    generators.push(canonical);
+                }
+            }
+        }
+
+        Self {
+            generators,
+            inverse_generators,
+        }
+    }
+}
+
+/// Normalizes the overall sign of a determinant-`1` matrix so that projectively-equal
+/// generators (`M` and `-M`, which represent the same element of PSL(2,R)) hash to the same
+/// `canonical_key`: flips the whole matrix unless the first nonzero entry among `a, c` is
+/// already positive.
+fn canonicalize_sign<T>(m: MoebiusTransformation<T>, numeric_threshold: Option<f64>) -> MoebiusTransformation<T>
+where
+    T: NumericAddIdentity + IsPositive + Neg<Output = T> + Copy,
+{
+    let negate = if !m.a.is_zero(numeric_threshold) {
+        !m.a.is_positive()
+    } else {
+        !m.c.is_positive()
+    };
+    if negate {
+        MoebiusTransformation::new(-m.a, -m.b, -m.c, -m.d)
+    } else {
+        m
     }
 }
 
@@ -259,12 +423,31 @@ where
     }
 }
 
+/// Quantizes the matrix entries to the numeric threshold so that numerically equal
+/// transformations hash to the same key, e.g. for `enumerate_reduced_words`'s deduplication.
+/// The overall sign is not normalized here: `M` and `-M` get different keys.
+impl CanonicalKey for MoebiusTransformation<f64> {
+    type Key = (i64, i64, i64, i64);
+
+    fn canonical_key(&self, numeric_threshold: Option<f64>) -> Self::Key {
+        let tol = numeric_threshold.unwrap_or(NUMERIC_THRESHOLD);
+        let quantize = |x: f64| (x / tol).round() as i64;
+        (
+            quantize(self.a),
+            quantize(self.b),
+            quantize(self.c),
+            quantize(self.d),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::FuchsianGroup;
+    use super::{Classification, FuchsianGroup};
     use crate::{
-        algebraic_extensions::Group,
+        algebraic_extensions::{Group, NumericMulIdentity},
         fuchsian_group::SpecialLinearMoebiusTransformation,
+        geometry::boundary::BoundaryPoint,
         group_action::{Action, Orbit},
         moebius::MoebiusTransformation,
     };
@@ -313,6 +496,23 @@ mod tests {
         assert_eq!(fg.generators.len(), 1);
     }
 
+    #[test]
+    fn test_create_projected_deduplicates_generators() {
+        // `m2` is a scalar multiple and `m3` the overall negation of `m1`: all three represent
+        // the same element of PSL(2,R) and should collapse to a single kept generator.
+        let m1 = MoebiusTransformation::<f64>::new(2.0, 1.0, 1.0, 1.0);
+        let m2 = MoebiusTransformation::<f64>::new(4.0, 2.0, 2.0, 2.0);
+        let m3 = MoebiusTransformation::<f64>::new(-2.0, -1.0, -1.0, -1.0);
+
+        let fg = FuchsianGroup::<f64>::create_projected(vec![m1, m2, m3], None);
+        assert_eq!(fg.generators.len(), 1);
+        assert_eq!(fg.inverse_generators().len(), 1);
+
+        let g = fg.generators[0];
+        let g_inv = fg.inverse_generators()[0];
+        assert!((g * g_inv).is_one(Some(1e-12)));
+    }
+
     #[test]
     fn test_action_real_line() {
         let m = MoebiusTransformation::<f64>::new(1.0, 2.0, 3.0, 4.0);
@@ -457,4 +657,99 @@ mod tests {
             assert_ne!(p.re, 0.0);
         }
     }
+
+    #[test]
+    fn test_orbit_ball() {
+        let g = MoebiusTransformation::<f64>::new(3.0, 1.0, 8.0, 3.0);
+        let h = MoebiusTransformation::<f64>::new(-3.0, 2.0, -5.0, 3.0);
+
+        let fuchsian_group = FuchsianGroup::create_from_valid(vec![g, h]);
+        let base_point = Complex64::new(1.0, 1.0);
+
+        let ball = Orbit::ball(&fuchsian_group, &base_point, 2);
+
+        // radius 0 is just the base point; radius 1 is the 2 generators and their 2 inverses;
+        // radius 2 extends each of those 4 by every signed generator but the one cancelling it.
+        assert_eq!(ball.iter().filter(|(_, depth)| *depth == 0).count(), 1);
+        assert_eq!(ball.iter().filter(|(_, depth)| *depth == 1).count(), 4);
+        assert_eq!(ball.iter().filter(|(_, depth)| *depth == 2).count(), 12);
+
+        for (p, _) in &ball {
+            assert!(p.im > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_classify_hyperbolic() {
+        let sl = SpecialLinearMoebiusTransformation::try_from(
+            MoebiusTransformation::<f64>::new(5.0, 0.0, 0.0, 0.2),
+            None,
+        )
+        .unwrap();
+
+        match sl.classify(None) {
+            Classification::Hyperbolic {
+                attracting,
+                repelling,
+            } => {
+                assert_eq!(attracting, BoundaryPoint::Infinity);
+                assert_eq!(repelling, BoundaryPoint::Regular(0.0));
+            }
+            _ => panic!("expected a hyperbolic classification"),
+        }
+    }
+
+    #[test]
+    fn test_classify_hyperbolic_diagonal_shear() {
+        // upper-triangular (c == 0), so one fixed point is infinity and the other solves
+        // (d - a) * z = b, i.e. z = b / (d - a).
+        let sl = SpecialLinearMoebiusTransformation::try_from(
+            MoebiusTransformation::<f64>::new(2.0, 3.0, 0.0, 0.5),
+            None,
+        )
+        .unwrap();
+
+        match sl.classify(None) {
+            Classification::Hyperbolic {
+                attracting,
+                repelling,
+            } => {
+                assert_eq!(attracting, BoundaryPoint::Infinity);
+                assert_eq!(repelling, BoundaryPoint::Regular(-2.0));
+            }
+            _ => panic!("expected a hyperbolic classification"),
+        }
+    }
+
+    #[test]
+    fn test_classify_parabolic() {
+        let sl = SpecialLinearMoebiusTransformation::try_from(
+            MoebiusTransformation::<f64>::new(1.0, 10.0, 0.0, 1.0),
+            None,
+        )
+        .unwrap();
+
+        match sl.classify(None) {
+            Classification::Parabolic { fixed } => assert_eq!(fixed, BoundaryPoint::Infinity),
+            _ => panic!("expected a parabolic classification"),
+        }
+    }
+
+    #[test]
+    fn test_classify_elliptic() {
+        // rotation by pi/2: [0, -1; 1, 0], fixed point at i
+        let sl = SpecialLinearMoebiusTransformation::try_from(
+            MoebiusTransformation::<f64>::new(0.0, -1.0, 1.0, 0.0),
+            None,
+        )
+        .unwrap();
+
+        match sl.classify(None) {
+            Classification::Elliptic { fixed } => {
+                assert_abs_diff_eq!(fixed.re, 0.0, epsilon = 1e-12);
+                assert_abs_diff_eq!(fixed.im, 1.0, epsilon = 1e-12);
+            }
+            _ => panic!("expected an elliptic classification"),
+        }
+    }
 }
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+use crate::ops::FloatPow;
 use num_complex::Complex;
 
 pub trait Distance<T> {
@@ -26,11 +28,12 @@ macro_rules! impl_hyperbolic_distance {
                 if self.im <= 0.0 || other.im <= 0.0 {
                     panic!("Distance only for the hyperbolic upper half space");
                 }
-                let eucl_dist =
-                    (self.re.dist(&other.re).powi(2) + self.im.dist(&other.im).powi(2)).sqrt();
-                let x = eucl_dist / (2.0 * (self.im * other.im)).sqrt();
+                let d_re = (self.re - other.re) as f64;
+                let d_im = (self.im - other.im) as f64;
+                let eucl_dist = crate::ops::sqrt(d_re.squared() + d_im.squared());
+                let x = eucl_dist / (2.0 * crate::ops::sqrt((self.im as f64) * (other.im as f64)));
                 // (inverse of sinh)(x) = ln(x + (x² + 1).sqrt)
-                2.0 * (x + (1.0 + x.powi(2)).sqrt()).ln() as f64
+                2.0 * crate::ops::ln(x + crate::ops::sqrt(1.0 + x.squared()))
             }
         }
     };
@@ -78,30 +81,90 @@ impl<T> Eq for EuclideanCircle<T> where T: PartialEq {}
 
 pub trait Drawable2d<T> {
     fn draw(&self, n_curve_points: usize) -> Vec<(T, T)>;
+
+    /// Adaptively subdivides the curve until the sagitta (chord-to-arc deviation) is below
+    /// `tol`, independent of the curve's scale, instead of sampling a fixed number of points.
+    fn draw_with_tolerance(&self, tol: T) -> Vec<(T, T)>;
+}
+
+fn circle_point(center: (f64, f64), radius: f64, theta: f64) -> (f64, f64) {
+    (
+        center.0 + radius * crate::ops::cos(theta),
+        center.1 + radius * crate::ops::sin(theta),
+    )
+}
+
+/// Recursively bisects `[theta0, theta1]` while the sagitta `r*(1 - cos(Δθ/2))` exceeds `tol`,
+/// appending `theta0`'s point at each leaf.
+fn flatten_arc_range(
+    center: (f64, f64),
+    radius: f64,
+    theta0: f64,
+    theta1: f64,
+    tol: f64,
+    out: &mut Vec<(f64, f64)>,
+) {
+    let sagitta = radius * (1.0 - crate::ops::cos((theta1 - theta0) / 2.0));
+    if sagitta > tol {
+        let theta_mid = (theta0 + theta1) / 2.0;
+        flatten_arc_range(center, radius, theta0, theta_mid, tol, out);
+        flatten_arc_range(center, radius, theta_mid, theta1, tol, out);
+    } else {
+        out.push(circle_point(center, radius, theta0));
+    }
+}
+
+/// Flattens the arc `[theta0, theta1]` of the circle at `center` with the given `radius` into a
+/// polyline whose sagitta (chord-to-arc deviation) is bounded by `tol`, independent of the
+/// circle's radius.
+pub fn flatten_arc_with_tolerance(
+    center: (f64, f64),
+    radius: f64,
+    theta0: f64,
+    theta1: f64,
+    tol: f64,
+) -> Vec<(f64, f64)> {
+    let mut points = Vec::new();
+    flatten_arc_range(center, radius, theta0, theta1, tol, &mut points);
+    points.push(circle_point(center, radius, theta1));
+    points
 }
 
 impl Drawable2d<f64> for EuclideanCircle<f64> {
     fn draw(&self, n_curve_points: usize) -> Vec<(f64, f64)> {
         let mut curve = Vec::with_capacity(n_curve_points);
-        let angle_step = 2.0 * std::f64::consts::PI / (n_curve_points as f64);
+        let angle_step = 2.0 * core::f64::consts::PI / (n_curve_points as f64);
         let mut angle: f64 = 0.0;
         for _ in 0..=n_curve_points {
             curve.push((
-                self.center.re + self.radius * angle.cos(),
-                self.center.im + self.radius * angle.sin(),
+                self.center.re + self.radius * crate::ops::cos(angle),
+                self.center.im + self.radius * crate::ops::sin(angle),
             ));
             angle += angle_step;
         }
         curve
     }
+
+    fn draw_with_tolerance(&self, tol: f64) -> Vec<(f64, f64)> {
+        flatten_arc_with_tolerance(
+            (self.center.re, self.center.im),
+            self.radius,
+            0.0,
+            2.0 * core::f64::consts::PI,
+            tol,
+        )
+    }
 }
 
 pub fn draw_euclidean_arc(center: f64, radius: f64, n_curve_pts: usize) -> Vec<(f64, f64)> {
     let mut curve = Vec::with_capacity(n_curve_pts);
-    let angle_step = std::f64::consts::PI / (n_curve_pts as f64);
+    let angle_step = core::f64::consts::PI / (n_curve_pts as f64);
     let mut angle: f64 = 0.0;
     for _ in 0..=n_curve_pts {
-        curve.push((center + radius * angle.cos(), radius * angle.sin()));
+        curve.push((
+            center + radius * crate::ops::cos(angle),
+            radius * crate::ops::sin(angle).max(0.0),
+        ));
         angle += angle_step;
     }
     curve
@@ -109,74 +172,51 @@ pub fn draw_euclidean_arc(center: f64, radius: f64, n_curve_pts: usize) -> Vec<(
 
 // TODO: impl Mid for complex, hyperbolic points
 
-/*/
-/// The [`Busemann function`](https://en.wikipedia.org/wiki/Busemann_function) of a boundary point `$\xi$` at infinity
-/// starting at the `base_point` (`$\gamma(0)$`).
-pub struct BusemannParams<T> {
-    pub base_point: Complex<T>,
-    pub boundary_point: BoundaryPoint<T>,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_flatten_arc_with_tolerance_endpoints() {
+        let points = flatten_arc_with_tolerance((0.0, 0.0), 1.0, 0.0, core::f64::consts::PI, 1e-6);
+        let first = points.first().unwrap();
+        let last = points.last().unwrap();
+        assert!((first.0 - 1.0).abs() < 1e-9);
+        assert!(first.1.abs() < 1e-9);
+        assert!((last.0 + 1.0).abs() < 1e-9);
+        assert!(last.1.abs() < 1e-9);
+    }
 
-impl<T> Default for BusemannParams<T>
-where
-    T: AddIdentity + MulIdentity,
-{
-    fn default() -> Self {
-        Self {
-            base_point: Complex {
-                re: AddIdentity::zero(),
-                im: MulIdentity::one(),
-            },
-            boundary_point: BoundaryPoint::Infinity,
-        }
+    #[test]
+    fn test_flatten_arc_with_tolerance_scales_with_radius() {
+        // a tighter tolerance, or a larger radius at fixed tolerance, both need more points.
+        let coarse = flatten_arc_with_tolerance((0.0, 0.0), 1.0, 0.0, core::f64::consts::PI, 1e-1);
+        let fine = flatten_arc_with_tolerance((0.0, 0.0), 1.0, 0.0, core::f64::consts::PI, 1e-6);
+        assert!(fine.len() > coarse.len());
+
+        let small_radius =
+            flatten_arc_with_tolerance((0.0, 0.0), 1.0, 0.0, core::f64::consts::PI, 1e-3);
+        let large_radius =
+            flatten_arc_with_tolerance((0.0, 0.0), 100.0, 0.0, core::f64::consts::PI, 1e-3);
+        assert!(large_radius.len() > small_radius.len());
     }
-}
 
-/// The `Busemann function` of a boundary point `$\xi$` serves as a height function on the hyperbolic space:
-/// `h(x) = | B(x) - B(b) |` for a fixed point `b`, or equivalently,
-/// fixing a level set `$B_0$` (through `b`) via `h(x) = | B(x) - B_0 |`
-/// which equals the hyperbolic distance between `x` and `B_0`.
-///
-/// Note that the level sets of this function correspond to horocycles based at `$\xi$` and exhaust the hyperbolic space.
-pub trait Height<T> {
-    fn height(&self) -> f64;
-}
+    #[test]
+    fn test_euclidean_circle_draw_with_tolerance() {
+        let circle = EuclideanCircle {
+            center: Complex::new(0.0, 0.0),
+            radius: 1.0,
+        };
+        let points = circle.draw_with_tolerance(1e-3);
+        assert!(points.len() > 2);
+    }
 
-// should be for UpperHalfSpace
-impl<T> Height<T> for Complex<T> {
-    fn height(&self) -> f64 {
-        1.0
+    #[test]
+    fn test_hyperbolic_distance_matches_arccosh_formula() {
+        // d(i, 2i) = arccosh(1 + |i - 2i|^2 / (2 * 1 * 2)) = arccosh(1.25) = ln(2)
+        let z = Complex::new(0.0, 1.0);
+        let w = Complex::new(0.0, 2.0);
+        assert_abs_diff_eq!(z.dist(&w), 2.0_f64.ln(), epsilon = 1e-10);
     }
 }
-
-// pub trait UpperHalfSpace: SetRestriction {
-//     fn condition(&self) -> bool;
-// }
-
-// impl<T> UpperHalfSpace for num_complex::Complex<T>
-// where
-//     T: AddIdentity,
-// {
-//     fn condition(&self) -> bool {
-//         self.im > AddIdentity::zero()
-//     }
-// }
-
-
-
-/// The [`HoroCycle`](https://en.wikipedia.org/wiki/Horocycle) in the hyperbolic (Poincare) upper half plane (within C)
-/// is either a `Euclidean` circle tangent to the boundary line, i.e. based at a boundary point (the touchpoint), or,
-/// the boundary of a half-plane parallel to the real line.
-/// `SpecialLinear` preserves horocycles (maps horocycles to horocycles).
-/// Given the [`Busemann function`](https://en.wikipedia.org/wiki/Busemann_function) of a boundary point `$\xi$`,
-/// the level sets of this function correspond to horocycles based at `$\xi$` and exhaust the hyperbolic space.
-/// Conversely, each `horocycle` is the level set of a Busemann function.
-///
-/// <b>Disclaimer</b>
-/// For simplicity, we will use a `height function` in the following which is the Busemann function based at `$\infty$`
-/// such that the Horocycle `Im(z) == 1` is the levelset of `0`.
-///
-
-*/
-
-*/
@@ -1,10 +1,10 @@
 use crate::{
     algebraic_extensions::{Numeric, NumericAddIdentity},
-    group_action::{Action, SpecialLinear},
+    group_action::Action,
     moebius::MoebiusTransformation,
     NUMERIC_THRESHOLD,
 };
-use std::{
+use core::{
     fmt::{self, Debug, Display},
     ops::Div,
 };
@@ -52,11 +52,13 @@ where
     }
 }
 
-/// Implement Action for Moebius transformations on the boundary.
+/// Implement Action for Moebius transformations on the boundary: for real `T` this is `ℝ ∪ {∞}`,
+/// but since `T` is unconstrained beyond the field-like `Numeric` bounds, it applies equally to
+/// `BoundaryPoint<Complex<f64>>`, i.e. the Riemann sphere `ℂ ∪ {∞}`, unlocking Kleinian-group
+/// (PSL(2,ℂ)) computations with the same code.
 impl<T> Action<BoundaryPoint<T>> for MoebiusTransformation<T>
 where
     T: Numeric + Div<Output = T> + NumericAddIdentity + Copy,
-    MoebiusTransformation<T>: SpecialLinear<T>,
 {
     fn map(&self, x: &BoundaryPoint<T>) -> BoundaryPoint<T> {
         match x {
@@ -134,4 +136,27 @@ mod tests {
         let b = BoundaryPoint::Regular(-1.0);
         assert_eq!(h.map(&b), BoundaryPoint::Regular(1.0));
     }
+
+    #[cfg(feature = "complex")]
+    #[test]
+    fn test_action_riemann_sphere() {
+        use num_complex::Complex;
+
+        // a Kleinian (PSL(2,C)) translation, acting on the Riemann sphere C ∪ {∞}
+        let h = MoebiusTransformation::new(
+            Complex::new(1.0, 0.0),
+            Complex::new(1.0, 1.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(1.0, 0.0),
+        );
+
+        let boundary_infty: BoundaryPoint<Complex<f64>> = BoundaryPoint::Infinity;
+        assert_eq!(h.map(&boundary_infty), BoundaryPoint::Infinity);
+
+        let boundary_regular = BoundaryPoint::Regular(Complex::new(0.0, 0.0));
+        assert_eq!(
+            h.map(&boundary_regular),
+            BoundaryPoint::Regular(Complex::new(1.0, 1.0))
+        );
+    }
 }
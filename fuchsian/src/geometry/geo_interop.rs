@@ -0,0 +1,99 @@
+//! Optional interoperability with the [`geo`](https://crates.io/crates/geo) ecosystem, gated
+//! behind the `geo` feature: converts the flattened polylines already produced by
+//! `Drawable2d::draw`/`draw_with_tolerance` into `geo_types::LineString`/`MultiLineString`, and
+//! renders those as Well-Known Text so an orbit can be loaded straight into GIS tools, PostGIS,
+//! or spatial test fixtures.
+#![cfg(feature = "geo")]
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use geo_types::{LineString, MultiLineString};
+
+use super::basics::Drawable2d;
+use crate::group_action::Orbit;
+
+/// A flattened polyline (as produced by `Drawable2d::draw`/`draw_with_tolerance`) as a
+/// `geo_types::LineString<f64>`.
+pub fn to_line_string(points: Vec<(f64, f64)>) -> LineString<f64> {
+    LineString::from(points)
+}
+
+/// Draws every point of an orbit of drawable curves (e.g. `Orbit<GeodesicLine<f64>>` or
+/// `Orbit<GeometricHorocCycle<f64>>`) into a `geo_types::MultiLineString<f64>`, one `LineString`
+/// per orbit point.
+pub fn orbit_to_multi_line_string<Space>(
+    orbit: &Orbit<Space>,
+    n_curve_points: usize,
+) -> MultiLineString<f64>
+where
+    Space: Drawable2d<f64>,
+{
+    let lines = orbit
+        .points
+        .iter()
+        .map(|space| to_line_string(space.draw(n_curve_points)))
+        .collect();
+    MultiLineString(lines)
+}
+
+/// Renders a `geo_types::MultiLineString<f64>` as Well-Known Text:
+/// `MULTILINESTRING((x y, ...), ...)`.
+pub fn to_wkt(multi: &MultiLineString<f64>) -> String {
+    let lines: Vec<String> = multi
+        .0
+        .iter()
+        .map(|line| {
+            let coords: Vec<String> = line.0.iter().map(|c| format!("{} {}", c.x, c.y)).collect();
+            format!("({})", coords.join(", "))
+        })
+        .collect();
+    format!("MULTILINESTRING({})", lines.join(", "))
+}
+
+/// Draws an orbit and renders it directly as a `MULTILINESTRING(...)` WKT string.
+pub fn orbit_to_wkt<Space>(orbit: &Orbit<Space>, n_curve_points: usize) -> String
+where
+    Space: Drawable2d<f64>,
+{
+    to_wkt(&orbit_to_multi_line_string(orbit, n_curve_points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::geodesics::{Arc, GeodesicLine};
+
+    #[test]
+    fn test_to_line_string_round_trips_points() {
+        let points = vec![(0.0, 0.0), (1.0, 1.0)];
+        let line_string = to_line_string(points);
+        assert_eq!(line_string.0.len(), 2);
+        assert_eq!((line_string.0[1].x, line_string.0[1].y), (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_orbit_to_multi_line_string_one_line_per_point() {
+        let orbit = Orbit {
+            points: vec![
+                GeodesicLine::Arc(Arc {
+                    center: 0.0,
+                    radius: 1.0,
+                }),
+                GeodesicLine::Line(1.0),
+            ],
+        };
+        let multi = orbit_to_multi_line_string(&orbit, 8);
+        assert_eq!(multi.0.len(), 2);
+    }
+
+    #[test]
+    fn test_to_wkt_format() {
+        let orbit = Orbit {
+            points: vec![GeodesicLine::Line(1.0)],
+        };
+        let wkt = orbit_to_wkt(&orbit, 4);
+        assert!(wkt.starts_with("MULTILINESTRING(("));
+        assert!(wkt.contains("1 0"));
+    }
+}
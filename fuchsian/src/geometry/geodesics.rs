@@ -1,5 +1,7 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use super::{
-    basics::{Distance, Mid},
+    basics::{self, draw_euclidean_arc, Distance, Drawable2d, Mid},
     boundary::BoundaryPoint,
 };
 use crate::{
@@ -64,6 +66,15 @@ where
     {
         (self.start.clone(), self.end.clone())
     }
+
+    /// Carries `self` to another geodesic under the action of `g`, e.g. a
+    /// `SpecialLinearMoebiusTransformation<T>`, by mapping its two boundary endpoints.
+    pub fn transform<G>(&self, g: &G) -> Self
+    where
+        G: Action<Self>,
+    {
+        g.map(self)
+    }
 }
 
 /// Implement `Action` for Moebius transformations on Geodesics.
@@ -128,6 +139,39 @@ impl<T> Arc<T> {
     }
 }
 
+impl Drawable2d<f64> for GeodesicLine<f64> {
+    /// Draws the semicircular arc (only its `sin θ ≥ 0` half, via `draw_euclidean_arc`) or, for
+    /// the vertical-ray case, a segment from the real axis up to Euclidean height
+    /// `n_curve_points` (reusing it as the sampled height, as `GeometricHorocCycle`'s `Line` case
+    /// does for its width).
+    fn draw(&self, n_curve_points: usize) -> Vec<(f64, f64)> {
+        match self {
+            GeodesicLine::Arc(Arc { center, radius }) => {
+                draw_euclidean_arc(*center, *radius, n_curve_points)
+            }
+            GeodesicLine::Line(touchpoint) => {
+                vec![(*touchpoint, 0.0), (*touchpoint, n_curve_points as f64)]
+            }
+        }
+    }
+
+    /// Adaptively flattens the arc, see `basics::flatten_arc_with_tolerance`; for the vertical-ray
+    /// case there is nothing to subdivide, so (as `draw` does with `n_curve_points`) `tol` is
+    /// reused as the sampled height.
+    fn draw_with_tolerance(&self, tol: f64) -> Vec<(f64, f64)> {
+        match self {
+            GeodesicLine::Arc(Arc { center, radius }) => basics::flatten_arc_with_tolerance(
+                (*center, 0.0),
+                *radius,
+                0.0,
+                core::f64::consts::PI,
+                tol,
+            ),
+            GeodesicLine::Line(touchpoint) => vec![(*touchpoint, 0.0), (*touchpoint, tol)],
+        }
+    }
+}
+
 // /// NOTE: for T in { i8, i32, i64 } etc, there is in general NO unique geodesic parametrization
 impl<T> From<GeodesicBoundary<T>> for GeodesicLine<T>
 where
@@ -154,7 +198,11 @@ mod tests {
     use super::{GeodesicBoundary, GeodesicLine};
     use crate::{
         fuchsian_group::{FuchsianGroup, SpecialLinearMoebiusTransformation},
-        geometry::{boundary::BoundaryPoint, geodesics::Arc},
+        geometry::{
+            basics::Drawable2d,
+            boundary::BoundaryPoint,
+            geodesics::Arc,
+        },
         group_action::{Action, Orbit},
         moebius,
         moebius::MoebiusTransformation,
@@ -233,6 +281,33 @@ mod tests {
         assert!(GeodesicLine::from(mg) == l);
     }
 
+    #[test]
+    fn test_draw_geodesic_line() {
+        let arc = GeodesicLine::Arc(Arc {
+            center: 0.0,
+            radius: 2.0,
+        });
+        let points = arc.draw(16);
+        assert_eq!(points.len(), 17);
+        assert!(points.iter().all(|(_, y)| *y >= 0.0));
+
+        let line = GeodesicLine::Line(1.0_f64);
+        assert_eq!(line.draw(5), vec![(1.0, 0.0), (1.0, 5.0)]);
+    }
+
+    #[test]
+    fn test_transform_geodesic() {
+        let sl =
+            SpecialLinearMoebiusTransformation::try_from(moebius!(f64, 2.0, 0.0, 0.0, 0.5), None)
+                .unwrap();
+
+        let g = GeodesicBoundary {
+            start: BoundaryPoint::Regular(1.0),
+            end: BoundaryPoint::Regular(-1.0),
+        };
+        assert!(GeodesicLine::from(g.transform(&sl)) == GeodesicLine::from(sl.map(&g)));
+    }
+
     #[test]
     fn test_geodesic_orbit_modular_group() {
         // see https://en.wikipedia.org/wiki/Modular_group
@@ -1,3 +1,6 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Div;
 use super::{
     basics::{Drawable2d, EuclideanCircle, Mid},
     boundary::BoundaryPoint,
@@ -9,7 +12,6 @@ use crate::{
     NUMERIC_THRESHOLD,
 };
 use num_complex::Complex;
-use std::ops::Div;
 
 /// A [`HoroCycle`](https://en.wikipedia.org/wiki/Horocycle) in the hyperbolic space
 /// is in general defined as the level set of a [`Busemann function`](https://en.wikipedia.org/wiki/Busemann_function) of a boundary point `$\xi$`,
@@ -191,6 +193,19 @@ where
     }
 }
 
+/// The [`Busemann function`](https://en.wikipedia.org/wiki/Busemann_function) of a boundary
+/// point `ξ`, evaluated at `z`: it serves as a signed height on the hyperbolic space whose level
+/// sets are exactly the horocycles based at `ξ`, normalized so that `busemann(Infinity, i) == 0`
+/// (the horocycle `Im(z) == 1` is the level set `0`).
+pub fn busemann(xi: BoundaryPoint<f64>, z: Complex<f64>) -> f64 {
+    match xi {
+        BoundaryPoint::Infinity => -crate::ops::ln(z.im),
+        BoundaryPoint::Regular(t) => {
+            crate::ops::ln((z - Complex::new(t, 0.0)).norm_sqr()) - crate::ops::ln(z.im)
+        }
+    }
+}
+
 impl Drawable2d<f64> for GeometricHorocCycle<f64> {
     fn draw(&self, n_curve_points: usize) -> Vec<(f64, f64)> {
         match self {
@@ -206,16 +221,57 @@ impl Drawable2d<f64> for GeometricHorocCycle<f64> {
             }
         }
     }
+
+    /// Adaptively flattens the tangency circle, see `EuclideanCircle::draw_with_tolerance`; for
+    /// the `Line` case there is nothing to subdivide, so (as `draw` does with `n_curve_points`)
+    /// `tol` is reused as the sampled width.
+    fn draw_with_tolerance(&self, tol: f64) -> Vec<(f64, f64)> {
+        match self {
+            GeometricHorocCycle::Line(height) => vec![(-tol, *height), (tol, *height)],
+            GeometricHorocCycle::TangencyCircle(tangency_circle) => {
+                let eucl_circle = EuclideanCircle::from(tangency_circle);
+                eucl_circle.draw_with_tolerance(tol)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        geometry::{boundary::BoundaryPoint, horocycle::GeometricHorocCycle},
+        geometry::{
+            boundary::BoundaryPoint,
+            horocycle::{busemann, GeometricHorocCycle},
+        },
         group_action::Action,
         moebius::MoebiusTransformation,
     };
     use approx::assert_abs_diff_eq;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_busemann_at_infinity() {
+        assert_abs_diff_eq!(
+            busemann(BoundaryPoint::Infinity, Complex::new(0.0, 1.0)),
+            0.0,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            busemann(BoundaryPoint::Infinity, Complex::new(0.0, std::f64::consts::E)),
+            -1.0,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_busemann_at_regular_point() {
+        // the tangency circle at 0 of diameter 1 passes through i, its level set 0.
+        assert_abs_diff_eq!(
+            busemann(BoundaryPoint::Regular(0.0), Complex::new(0.0, 1.0)),
+            0.0,
+            epsilon = 1e-12
+        );
+    }
 
     #[test]
     fn test_action_horocyclic() {
@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+use num_complex::Complex;
+
+/// A point in the Euclidean plane exposing its Cartesian coordinates, so that geometric
+/// algorithms (convex hull, etc.) can operate on it without depending on a concrete type.
+pub trait PlanarPoint {
+    fn x(&self) -> f64;
+    fn y(&self) -> f64;
+}
+
+impl PlanarPoint for Complex<f64> {
+    fn x(&self) -> f64 {
+        self.re
+    }
+
+    fn y(&self) -> f64 {
+        self.im
+    }
+}
+
+fn cross<P: PlanarPoint>(a: &P, b: &P, c: &P) -> f64 {
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+/// Computes the 2D convex hull of `points` via
+/// [Andrew's monotone chain](https://en.wikipedia.org/wiki/Convex_hull_algorithms#Monotone_chain),
+/// returning the hull vertices in counter-clockwise order.
+///
+/// Fewer than three distinct points are returned sorted by `(x, y)` with exact duplicates
+/// removed; fully collinear input degenerates to the two extreme points of the line.
+pub fn convex_hull<P>(points: &[P]) -> Vec<P>
+where
+    P: PlanarPoint + Clone + PartialEq,
+{
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x()
+            .partial_cmp(&b.x())
+            .unwrap()
+            .then_with(|| a.y().partial_cmp(&b.y()).unwrap())
+    });
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut lower: Vec<P> = Vec::new();
+    for p in sorted.iter() {
+        while lower.len() >= 2 && cross(&lower[lower.len() - 2], &lower[lower.len() - 1], p) <= 0.0
+        {
+            lower.pop();
+        }
+        lower.push(p.clone());
+    }
+
+    let mut upper: Vec<P> = Vec::new();
+    for p in sorted.iter().rev() {
+        while upper.len() >= 2 && cross(&upper[upper.len() - 2], &upper[upper.len() - 1], p) <= 0.0
+        {
+            upper.pop();
+        }
+        upper.push(p.clone());
+    }
+
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convex_hull;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_hull_of_square() {
+        let points = vec![
+            Complex::new(0.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(2.0, 2.0),
+            Complex::new(0.0, 2.0),
+            Complex::new(1.0, 1.0), // interior point, should be dropped
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+    }
+
+    #[test]
+    fn test_hull_fewer_than_three_points() {
+        let points = vec![Complex::new(0.0, 0.0), Complex::new(1.0, 1.0)];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, points);
+    }
+
+    #[test]
+    fn test_hull_collinear_points() {
+        let points = vec![
+            Complex::new(0.0, 0.0),
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, vec![Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_hull_duplicate_points() {
+        let points = vec![
+            Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(1.0, 1.0),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, vec![Complex::new(0.0, 0.0), Complex::new(1.0, 1.0)]);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod basics;
+pub mod boundary;
+pub mod geo_interop;
+pub mod geodesics;
+pub mod horocycle;
+pub mod hull;
+pub mod render;
+pub mod svg;
+pub mod upper_half_plane;
@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+use super::upper_half_plane::UpperHalfPlanePoint;
+use crate::group_action::Orbit;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+/// A minimal binary (P6) PPM image: `width x height` RGB pixels, row-major, top row first.
+pub struct PPM {
+    pub width: usize,
+    pub height: usize,
+    /// RGB triples, row-major: the pixel at `(x, y)` is `buffer[3*(y*width+x) .. +3]`.
+    pub buffer: Vec<u8>,
+}
+
+impl PPM {
+    /// A `width x height` image filled with `background`.
+    pub fn new(width: usize, height: usize, background: [u8; 3]) -> Self {
+        let mut buffer = Vec::with_capacity(3 * width * height);
+        for _ in 0..width * height {
+            buffer.extend_from_slice(&background);
+        }
+        Self {
+            width,
+            height,
+            buffer,
+        }
+    }
+
+    fn set_pixel(&mut self, x: usize, y: usize, color: [u8; 3]) {
+        if x < self.width && y < self.height {
+            let i = 3 * (y * self.width + x);
+            self.buffer[i..i + 3].copy_from_slice(&color);
+        }
+    }
+
+    /// Writes the image in the binary PPM (P6) format. Requires the `std` feature: unlike the
+    /// rest of this module, encoding to an `io::Write` sink has no `alloc`-only equivalent.
+    #[cfg(feature = "std")]
+    pub fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.buffer)
+    }
+}
+
+/// Configuration for rasterizing the upper half-plane onto a `PPM`.
+pub struct RasterConfig {
+    pub width: usize,
+    pub height: usize,
+    pub background: [u8; 3],
+    pub point_color: [u8; 3],
+    /// The Euclidean region `x_range x [0, y_max]` of the upper half-plane mapped onto the
+    /// image, with `y` increasing upward.
+    pub x_range: (f64, f64),
+    pub y_max: f64,
+}
+
+impl Default for RasterConfig {
+    fn default() -> Self {
+        Self {
+            width: 512,
+            height: 512,
+            background: [255, 255, 255],
+            point_color: [0, 0, 0],
+            x_range: (-2.0, 2.0),
+            y_max: 4.0,
+        }
+    }
+}
+
+/// Maps an upper half-plane coordinate to a pixel, or `None` if it falls outside
+/// `config.x_range x [0, config.y_max]`.
+fn to_pixel(x: f64, y: f64, config: &RasterConfig) -> Option<(usize, usize)> {
+    let (x_min, x_max) = config.x_range;
+    if x < x_min || x > x_max || y < 0.0 || y > config.y_max {
+        return None;
+    }
+    let px = ((x - x_min) / (x_max - x_min) * (config.width as f64)) as usize;
+    let py = ((1.0 - y / config.y_max) * (config.height as f64)) as usize;
+    Some((px.min(config.width - 1), py.min(config.height - 1)))
+}
+
+/// Rasterizes the orbit of a seed point under words in the generators, plotting each orbit
+/// point as a single pixel of `config.point_color`.
+pub fn orbit_to_ppm(orbit: &Orbit<UpperHalfPlanePoint<f64>>, config: &RasterConfig) -> PPM {
+    let mut ppm = PPM::new(config.width, config.height, config.background);
+    for point in &orbit.points {
+        if let Some((x, y)) = to_pixel(point.x(), point.y(), config) {
+            ppm.set_pixel(x, y, config.point_color);
+        }
+    }
+    ppm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppm_write_format() {
+        let mut ppm = PPM::new(2, 1, [255, 255, 255]);
+        ppm.set_pixel(0, 0, [1, 2, 3]);
+
+        let mut out = Vec::new();
+        ppm.write(&mut out).unwrap();
+
+        assert_eq!(out, b"P6\n2 1\n255\n\x01\x02\x03\xff\xff\xff");
+    }
+
+    #[test]
+    fn test_to_pixel_out_of_range_is_none() {
+        let config = RasterConfig::default();
+        assert!(to_pixel(-100.0, 1.0, &config).is_none());
+        assert!(to_pixel(0.0, -1.0, &config).is_none());
+    }
+
+    #[test]
+    fn test_orbit_to_ppm_plots_points_in_range() {
+        let config = RasterConfig::default();
+        let orbit = Orbit {
+            points: vec![
+                UpperHalfPlanePoint::new(num_complex::Complex::new(0.0, 1.0)),
+                UpperHalfPlanePoint::new(num_complex::Complex::new(-100.0, 1.0)),
+            ],
+        };
+
+        let ppm = orbit_to_ppm(&orbit, &config);
+        assert_ne!(ppm.buffer, vec![255u8; 3 * config.width * config.height]);
+    }
+}
@@ -0,0 +1,394 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use super::basics::{Drawable2d, EuclideanCircle};
+use super::geodesics::{Arc, GeodesicLine};
+use super::horocycle::GeometricHorocCycle;
+use crate::group_action::Orbit;
+use num_complex::Complex;
+
+/// Configuration for rendering geodesics/orbits to SVG.
+pub struct SvgConfig {
+    pub stroke: String,
+    pub stroke_width: f64,
+    /// Maximum chord-to-arc deviation tolerated when flattening a half-circle into a polyline.
+    pub tolerance: f64,
+    /// The Euclidean height at which a vertical `GeodesicLine::Line` is drawn up to.
+    pub line_height: f64,
+    /// Map every point through the Cayley transform, drawing the Poincaré disc model instead of
+    /// the upper half plane.
+    pub disc_model: bool,
+}
+
+impl Default for SvgConfig {
+    fn default() -> Self {
+        Self {
+            stroke: "black".to_string(),
+            stroke_width: 1.0,
+            tolerance: 1e-3,
+            line_height: 5.0,
+            disc_model: false,
+        }
+    }
+}
+
+/// A cyclic palette used to color an orbit's paths by the index (a proxy for word length, absent
+/// an explicit depth) of each point within `Orbit::points`.
+pub struct ColorRamp {
+    pub colors: Vec<String>,
+}
+
+impl ColorRamp {
+    /// The color for `depth`, cycling through `colors` via `depth % colors.len()`.
+    pub fn color_at(&self, depth: usize) -> &str {
+        &self.colors[depth % self.colors.len()]
+    }
+}
+
+impl Default for ColorRamp {
+    fn default() -> Self {
+        Self {
+            colors: vec!["black".to_string()],
+        }
+    }
+}
+
+/// The Cayley transform `w = (z - i) / (z + i)`, conjugating the upper half plane onto the unit disc.
+pub fn cayley_transform(z: Complex<f64>) -> Complex<f64> {
+    (z - Complex::new(0.0, 1.0)) / (z + Complex::new(0.0, 1.0))
+}
+
+/// Flattens the upper half-circle (`theta in [0, pi]`) of center `center` and `radius` into a
+/// polyline whose chord-to-arc deviation is bounded by `tol`, via
+/// `basics::flatten_arc_with_tolerance`.
+pub fn flatten_arc(center: f64, radius: f64, tol: f64) -> Vec<(f64, f64)> {
+    super::basics::flatten_arc_with_tolerance(
+        (center, 0.0),
+        radius,
+        0.0,
+        core::f64::consts::PI,
+        tol,
+    )
+}
+
+/// The polyline representation of a `GeodesicLine`: a flattened half-circle for `Arc`, or the
+/// vertical segment up to `line_height` for `Line`.
+pub fn geodesic_polyline(line: &GeodesicLine<f64>, tol: f64, line_height: f64) -> Vec<(f64, f64)> {
+    match line {
+        GeodesicLine::Arc(Arc { center, radius }) => flatten_arc(*center, *radius, tol),
+        GeodesicLine::Line(touchpoint) => vec![(*touchpoint, 0.0), (*touchpoint, line_height)],
+    }
+}
+
+fn maybe_cayley(point: (f64, f64), disc_model: bool) -> (f64, f64) {
+    if disc_model {
+        let w = cayley_transform(Complex::new(point.0, point.1));
+        (w.re, w.im)
+    } else {
+        point
+    }
+}
+
+fn polyline_path_d(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, (x, y))| {
+            if i == 0 {
+                format!("M {} {}", x, y)
+            } else {
+                format!("L {} {}", x, y)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bounding_box(points: &[(f64, f64)]) -> (f64, f64, f64, f64) {
+    let mut min_x = f64::INFINITY;
+    let mut min_y = f64::INFINITY;
+    let mut max_x = f64::NEG_INFINITY;
+    let mut max_y = f64::NEG_INFINITY;
+    for (x, y) in points {
+        min_x = min_x.min(*x);
+        min_y = min_y.min(*y);
+        max_x = max_x.max(*x);
+        max_y = max_y.max(*y);
+    }
+    (min_x, min_y, max_x, max_y)
+}
+
+/// Renders a single geodesic as an SVG `<path>`, flattened to a polyline.
+pub fn geodesic_to_svg_path(line: &GeodesicLine<f64>, config: &SvgConfig) -> String {
+    let points: Vec<(f64, f64)> = geodesic_polyline(line, config.tolerance, config.line_height)
+        .into_iter()
+        .map(|p| maybe_cayley(p, config.disc_model))
+        .collect();
+    format!(
+        "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" />",
+        polyline_path_d(&points),
+        config.stroke,
+        config.stroke_width
+    )
+}
+
+/// Renders a full orbit of geodesics as a standalone SVG document, with a `viewBox` fitted to
+/// the drawn primitives.
+pub fn orbit_to_svg(orbit: &Orbit<GeodesicLine<f64>>, config: &SvgConfig) -> String {
+    let polylines: Vec<Vec<(f64, f64)>> = orbit
+        .points
+        .iter()
+        .map(|line| {
+            geodesic_polyline(line, config.tolerance, config.line_height)
+                .into_iter()
+                .map(|p| maybe_cayley(p, config.disc_model))
+                .collect()
+        })
+        .collect();
+
+    let all_points: Vec<(f64, f64)> = polylines.iter().flatten().copied().collect();
+    let (min_x, min_y, max_x, max_y) = bounding_box(&all_points);
+
+    let paths = polylines
+        .iter()
+        .map(|points| {
+            format!(
+                "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" />",
+                polyline_path_d(points),
+                config.stroke,
+                config.stroke_width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n{}\n</svg>",
+        min_x,
+        min_y,
+        max_x - min_x,
+        max_y - min_y,
+        paths
+    )
+}
+
+/// Renders a geodesic as an SVG `<path>` using an elliptical-arc (`A`) command for the half-circle
+/// case, instead of a flattened polyline. Only supported in the upper half plane: a Cayley
+/// transform does not carry a circle of this radius to another circle of the same radius, so
+/// `config.disc_model` is ignored here and `geodesic_to_svg_path` should be used for the disc
+/// model instead.
+pub fn geodesic_to_svg_arc_path(
+    line: &GeodesicLine<f64>,
+    config: &SvgConfig,
+    stroke: &str,
+) -> String {
+    let d = match line {
+        GeodesicLine::Arc(Arc { center, radius }) => format!(
+            "M {} {} A {} {} 0 0 1 {} {}",
+            center + radius,
+            0.0,
+            radius,
+            radius,
+            center - radius,
+            0.0
+        ),
+        GeodesicLine::Line(touchpoint) => format!(
+            "M {} {} L {} {}",
+            touchpoint, 0.0, touchpoint, config.line_height
+        ),
+    };
+    format!(
+        "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" />",
+        d, stroke, config.stroke_width
+    )
+}
+
+/// Renders a horocycle as an SVG `<path>` using elliptical-arc commands for a `TangencyCircle`
+/// (two half-circle arcs, since a single `A` command cannot close a full circle) or a straight
+/// segment for `Line`, reusing `config.line_height` as the segment's half-width (as
+/// `GeometricHorocCycle::draw` reuses `n_curve_points` for the same purpose).
+pub fn horocycle_to_svg_arc_path(
+    horocycle: &GeometricHorocCycle<f64>,
+    config: &SvgConfig,
+    stroke: &str,
+) -> String {
+    let d = match horocycle {
+        GeometricHorocCycle::Line(height) => format!(
+            "M {} {} L {} {}",
+            -config.line_height, height, config.line_height, height
+        ),
+        GeometricHorocCycle::TangencyCircle(tangency_circle) => {
+            let circle = EuclideanCircle::from(tangency_circle);
+            let (cx, cy, r) = (circle.center.re, circle.center.im, circle.radius);
+            format!(
+                "M {} {} A {} {} 0 1 1 {} {} A {} {} 0 1 1 {} {}",
+                cx + r,
+                cy,
+                r,
+                r,
+                cx - r,
+                cy,
+                r,
+                r,
+                cx + r,
+                cy
+            )
+        }
+    };
+    format!(
+        "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" />",
+        d, stroke, config.stroke_width
+    )
+}
+
+/// Renders a full orbit of geodesics as a standalone SVG document using arc-command paths (see
+/// `geodesic_to_svg_arc_path`), coloring the `i`-th path via `ramp.color_at(i)`. The `viewBox` is
+/// still fitted from the flattened polyline representation, since `bounding_box` needs sampled
+/// points rather than arc parameters.
+pub fn orbit_to_svg_arcs(
+    orbit: &Orbit<GeodesicLine<f64>>,
+    config: &SvgConfig,
+    ramp: &ColorRamp,
+) -> String {
+    let all_points: Vec<(f64, f64)> = orbit
+        .points
+        .iter()
+        .flat_map(|line| geodesic_polyline(line, config.tolerance, config.line_height))
+        .collect();
+    let (min_x, min_y, max_x, max_y) = bounding_box(&all_points);
+
+    let paths = orbit
+        .points
+        .iter()
+        .enumerate()
+        .map(|(depth, line)| geodesic_to_svg_arc_path(line, config, ramp.color_at(depth)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n{}\n</svg>",
+        min_x,
+        min_y,
+        max_x - min_x,
+        max_y - min_y,
+        paths
+    )
+}
+
+/// Renders a full orbit of horocycles as a standalone SVG document using arc-command paths (see
+/// `horocycle_to_svg_arc_path`), coloring the `i`-th path via `ramp.color_at(i)`.
+pub fn horocycle_orbit_to_svg_arcs(
+    orbit: &Orbit<GeometricHorocCycle<f64>>,
+    config: &SvgConfig,
+    ramp: &ColorRamp,
+) -> String {
+    let all_points: Vec<(f64, f64)> = orbit
+        .points
+        .iter()
+        .flat_map(|horocycle| horocycle.draw_with_tolerance(config.tolerance))
+        .collect();
+    let (min_x, min_y, max_x, max_y) = bounding_box(&all_points);
+
+    let paths = orbit
+        .points
+        .iter()
+        .enumerate()
+        .map(|(depth, horocycle)| {
+            horocycle_to_svg_arc_path(horocycle, config, ramp.color_at(depth))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n{}\n</svg>",
+        min_x,
+        min_y,
+        max_x - min_x,
+        max_y - min_y,
+        paths
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_arc_endpoints() {
+        let points = flatten_arc(0.0, 1.0, 1e-6);
+        let first = points.first().unwrap();
+        let last = points.last().unwrap();
+        assert!((first.0 - 1.0).abs() < 1e-9);
+        assert!(first.1.abs() < 1e-9);
+        assert!((last.0 + 1.0).abs() < 1e-9);
+        assert!(last.1.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flatten_arc_tighter_tolerance_yields_more_points() {
+        let coarse = flatten_arc(0.0, 1.0, 1e-1);
+        let fine = flatten_arc(0.0, 1.0, 1e-6);
+        assert!(fine.len() > coarse.len());
+    }
+
+    #[test]
+    fn test_geodesic_polyline_line_variant() {
+        let line = GeodesicLine::Line(2.0);
+        let points = geodesic_polyline(&line, 1e-3, 5.0);
+        assert_eq!(points, vec![(2.0, 0.0), (2.0, 5.0)]);
+    }
+
+    #[test]
+    fn test_geodesic_to_svg_path_contains_moveto() {
+        let line = GeodesicLine::Arc(Arc {
+            center: 0.0,
+            radius: 1.0,
+        });
+        let svg = geodesic_to_svg_path(&line, &SvgConfig::default());
+        assert!(svg.starts_with("<path d=\"M"));
+    }
+
+    #[test]
+    fn test_geodesic_to_svg_arc_path_uses_arc_command() {
+        let line = GeodesicLine::Arc(Arc {
+            center: 0.0,
+            radius: 2.0,
+        });
+        let svg = geodesic_to_svg_arc_path(&line, &SvgConfig::default(), "red");
+        assert!(svg.contains("A 2 2 0 0 1"));
+        assert!(svg.contains("stroke=\"red\""));
+    }
+
+    #[test]
+    fn test_horocycle_to_svg_arc_path_tangency_circle_closes() {
+        use crate::geometry::{boundary::BoundaryPoint, horocycle::GeometricHorocCycle};
+
+        let horocycle = GeometricHorocCycle::new(BoundaryPoint::Regular(0.0), 2.0);
+        let svg = horocycle_to_svg_arc_path(&horocycle, &SvgConfig::default(), "blue");
+        assert_eq!(svg.matches(" A ").count(), 2);
+    }
+
+    #[test]
+    fn test_color_ramp_cycles() {
+        let ramp = ColorRamp {
+            colors: vec!["a".to_string(), "b".to_string()],
+        };
+        assert_eq!(ramp.color_at(0), "a");
+        assert_eq!(ramp.color_at(1), "b");
+        assert_eq!(ramp.color_at(2), "a");
+    }
+
+    #[test]
+    fn test_orbit_to_svg_arcs_contains_one_path_per_point() {
+        let orbit = Orbit {
+            points: vec![
+                GeodesicLine::Arc(Arc {
+                    center: 0.0,
+                    radius: 1.0,
+                }),
+                GeodesicLine::Line(1.0),
+            ],
+        };
+        let ramp = ColorRamp::default();
+        let svg = orbit_to_svg_arcs(&orbit, &SvgConfig::default(), &ramp);
+        assert_eq!(svg.matches("<path").count(), 2);
+    }
+}
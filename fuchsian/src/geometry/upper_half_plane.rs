@@ -0,0 +1,132 @@
+use core::ops::Div;
+use super::basics::Distance;
+use crate::{
+    algebraic_extensions::{IsPositive, Numeric},
+    group_action::Action,
+    moebius::MoebiusTransformation,
+    set_extensions::SetRestriction,
+};
+use num_complex::Complex;
+
+/// An interior point `z = x + i·y` of the upper half-plane model of the hyperbolic plane, with
+/// the invariant `y > 0` enforced via `SetRestriction`. Complements `BoundaryPoint<T>`, which
+/// models the boundary `ℝ ∪ {∞}` rather than the interior.
+pub struct UpperHalfPlanePoint<T> {
+    z: Complex<T>,
+}
+
+impl<T> UpperHalfPlanePoint<T> {
+    /// Constructs a point without checking the `Im(z) > 0` invariant; use `try_new` (via
+    /// `SetRestriction`) to enforce it.
+    pub fn new(z: Complex<T>) -> Self {
+        Self { z }
+    }
+
+    pub fn z(&self) -> Complex<T>
+    where
+        T: Copy,
+    {
+        self.z
+    }
+
+    pub fn x(&self) -> T
+    where
+        T: Copy,
+    {
+        self.z.re
+    }
+
+    pub fn y(&self) -> T
+    where
+        T: Copy,
+    {
+        self.z.im
+    }
+}
+
+impl<T> SetRestriction for UpperHalfPlanePoint<T>
+where
+    T: IsPositive,
+{
+    fn condition(&self) -> bool {
+        self.z.im.is_positive()
+    }
+}
+
+impl<T> PartialEq for UpperHalfPlanePoint<T>
+where
+    Complex<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.z == other.z
+    }
+}
+impl<T> Eq for UpperHalfPlanePoint<T> where Complex<T>: PartialEq {}
+impl<T> Copy for UpperHalfPlanePoint<T> where T: Copy {}
+impl<T> Clone for UpperHalfPlanePoint<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            z: self.z.clone(),
+        }
+    }
+}
+
+/// `SpecialLinear` maps the upper half-plane to itself, so the action stays within
+/// `UpperHalfPlanePoint`; this delegates to the existing `Action<Complex<T>>` impl.
+impl<T> Action<UpperHalfPlanePoint<T>> for MoebiusTransformation<T>
+where
+    T: Numeric + Copy + PartialEq,
+    Complex<T>: Div<Output = Complex<T>>,
+{
+    fn map(&self, x: &UpperHalfPlanePoint<T>) -> UpperHalfPlanePoint<T> {
+        UpperHalfPlanePoint::new(self.map(&x.z))
+    }
+}
+
+impl UpperHalfPlanePoint<f64> {
+    /// The Poincare (hyperbolic) distance `d(z, w) = arccosh(1 + |z−w|² / (2·Im(z)·Im(w)))`,
+    /// via the existing hyperbolic `Distance` impl for `Complex<f64>`.
+    pub fn distance(&self, other: &Self) -> f64 {
+        self.z.dist(&other.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpperHalfPlanePoint;
+    use crate::{
+        group_action::Action, moebius::MoebiusTransformation, set_extensions::SetRestriction,
+    };
+    use approx::assert_abs_diff_eq;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_try_new_enforces_upper_half_plane() {
+        let z = UpperHalfPlanePoint::try_new(UpperHalfPlanePoint::new(Complex::new(1.0, 2.0)));
+        assert!(z.is_some());
+
+        let w = UpperHalfPlanePoint::try_new(UpperHalfPlanePoint::new(Complex::new(1.0, -2.0)));
+        assert!(w.is_none());
+    }
+
+    #[test]
+    fn test_distance_is_zero_for_coincident_points() {
+        let z = UpperHalfPlanePoint::new(Complex::new(1.0, 2.0));
+        assert_eq!(z.distance(&z), 0.0);
+    }
+
+    #[test]
+    fn test_distance_is_preserved_by_the_action() {
+        let m = MoebiusTransformation::<f64>::new(3.0, 2.0, 4.0, 3.0);
+        let z = UpperHalfPlanePoint::new(Complex::new(1.0, 3.0));
+        let w = UpperHalfPlanePoint::new(Complex::new(-2.0, 1.0));
+
+        let d = z.distance(&w);
+        let d_mapped = m.map(&z).distance(&m.map(&w));
+
+        assert_abs_diff_eq!(d, d_mapped, epsilon = 1e-10);
+    }
+}
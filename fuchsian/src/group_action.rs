@@ -1,5 +1,6 @@
 use crate::{
     algebraic_extensions::{Group, MulIdentity},
+    geometry::hull::{convex_hull, PlanarPoint},
     set_extensions::{SetRestriction, Wrapper},
 };
 use rand::{
@@ -51,6 +52,10 @@ pub enum PickGeneratorMode {
     #[default]
     Sequential,
     Random,
+    /// Like `Random`, but never follows a generator with its own inverse: `RandomPicker` draws
+    /// independently, so it frequently cancels its own last step and biases the orbit back
+    /// towards `base_point`. See `NonBacktrackingPicker`.
+    NonBacktracking,
 }
 
 struct SequentialPicker<'a, G> {
@@ -132,6 +137,68 @@ where
     }
 }
 
+/// Draws generator indices uniformly at random, but, once a first generator has been picked,
+/// never draws that generator's own inverse next: `generators` is laid out as `[g_1, ..., g_k,
+/// g_1⁻¹, ..., g_k⁻¹]` (see `Orbit::sample`), so `n_generators` (`k`) is enough to map an index
+/// to its inverse's index and exclude it via rejection sampling.
+struct NonBacktrackingPicker<'a, G> {
+    generators: &'a Vec<G>,
+    cursor: usize,
+    max_n: usize,
+    n_generators: usize,
+    last_idx: Option<usize>,
+    rand_iter: DistIter<Uniform<usize>, ThreadRng, usize>,
+}
+
+impl<'a, G> NonBacktrackingPicker<'a, G>
+where
+    G: Clone,
+{
+    fn new(generators: &'a Vec<G>, max_n: usize, n_generators: usize) -> Self {
+        let rand_iter = random_iter(generators.len());
+
+        Self {
+            max_n,
+            cursor: 0,
+            n_generators,
+            last_idx: None,
+            rand_iter,
+            generators,
+        }
+    }
+
+    fn inverse_index(&self, idx: usize) -> usize {
+        if idx < self.n_generators {
+            idx + self.n_generators
+        } else {
+            idx - self.n_generators
+        }
+    }
+}
+
+impl<'a, G> Iterator for NonBacktrackingPicker<'a, G>
+where
+    G: Clone,
+{
+    type Item = G;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursor >= self.max_n {
+            return None;
+        }
+        self.cursor += 1;
+
+        let forbidden = self.last_idx.map(|idx| self.inverse_index(idx));
+        let mut grp_idx = self.rand_iter.next().unwrap();
+        while Some(grp_idx) == forbidden {
+            grp_idx = self.rand_iter.next().unwrap();
+        }
+
+        self.last_idx = Some(grp_idx);
+        Some(self.generators[grp_idx].clone())
+    }
+}
+
 // TODO: make it feature dependent
 fn random_iter(u_bound: usize) -> DistIter<Uniform<usize>, ThreadRng, usize> {
     use rand::{thread_rng, Rng};
@@ -197,7 +264,8 @@ where
     {
         let mut points = Vec::with_capacity(n_points);
 
-        let mut generators = Vec::with_capacity(2 * group.generators().len());
+        let n_generators = group.generators().len();
+        let mut generators = Vec::with_capacity(2 * n_generators);
         if group.generators().len() > 1 {
             // order of adding generators is important, so that an element with its inverse don't cancel each other immediately
             for g in group.generators().iter() {
@@ -227,15 +295,175 @@ where
                     points.push(point_cursor.clone());
                 }
             }
+            PickGeneratorMode::NonBacktracking => {
+                let generator = NonBacktrackingPicker::new(&generators, n_points, n_generators);
+                for g in generator {
+                    point_cursor = g.map(&point_cursor);
+                    points.push(point_cursor.clone());
+                }
+            }
         };
 
         Self { points }
     }
+
+    /// Breadth-first, reduced-word enumeration of the orbit of `base_point`: the deterministic
+    /// counterpart to `sample`'s random/sequential walks. Visits the image of `base_point` under
+    /// every element of word length `<= radius` in the generators, skipping `g · g⁻¹` (which
+    /// would immediately cancel the previous step) via the same inverse-index bookkeeping as
+    /// `enumerate_reduced_words`, and tags each point with the word length at which it was
+    /// reached, so callers can e.g. color an orbit by distance from `base_point`. Unlike
+    /// `enumerate_reduced_words`, points are not deduplicated: two distinct reduced words are
+    /// always both visited, even if a group relation happens to map them to the same point.
+    pub fn ball<Group>(group: &Group, base_point: &Space, radius: usize) -> Vec<(Space, usize)>
+    where
+        Group: FinitelyGeneratedGroup,
+        Group::GroupElement: Action<Space> + Clone,
+        Space: Clone,
+    {
+        let generators = group.generators();
+        let n_generators = generators.len();
+
+        // indices [0, n_generators) are the generators, [n_generators, 2*n_generators) their inverses.
+        let mut signed_generators = Vec::with_capacity(2 * n_generators);
+        signed_generators.extend(generators.iter().cloned());
+        signed_generators.extend(generators.iter().map(|g| g.inv()));
+
+        let inverse_index = |idx: usize| -> usize {
+            if idx < n_generators {
+                idx + n_generators
+            } else {
+                idx - n_generators
+            }
+        };
+
+        let mut result = vec![(base_point.clone(), 0usize)];
+        let mut frontier = vec![(base_point.clone(), None::<usize>)];
+        let mut depth = 0;
+
+        while !frontier.is_empty() && depth < radius {
+            depth += 1;
+            let mut next_frontier = Vec::with_capacity(frontier.len() * n_generators);
+            for (point, last_idx) in frontier {
+                for (idx, g) in signed_generators.iter().enumerate() {
+                    if last_idx == Some(inverse_index(idx)) {
+                        // skip g·g⁻¹, which would cancel back to the previous word
+                        continue;
+                    }
+                    let next_point = g.map(&point);
+                    result.push((next_point.clone(), depth));
+                    next_frontier.push((next_point, Some(idx)));
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        result
+    }
+}
+
+/// A group element that can be rounded into a hashable key, so that elements which agree up to
+/// the numeric threshold can be deduplicated via a `HashSet` rather than pairwise comparison.
+pub trait CanonicalKey {
+    type Key: std::hash::Hash + Eq;
+
+    fn canonical_key(&self, numeric_threshold: Option<f64>) -> Self::Key;
+}
+
+/// Breadth-first, reduced-word enumeration of the elements generated by a
+/// `FinitelyGeneratedGroup`: precomputes each generator's inverse once, never follows a
+/// generator with its own inverse (which would immediately cancel back to the previous word),
+/// and deduplicates emitted elements via their `canonical_key`.
+///
+/// Stops once `max_elements` distinct elements (including the identity) have been produced, or
+/// once the reduced-word frontier is exhausted.
+pub fn enumerate_reduced_words<G>(
+    group: &G,
+    max_elements: usize,
+    numeric_threshold: Option<f64>,
+) -> Vec<G::GroupElement>
+where
+    G: FinitelyGeneratedGroup,
+    G::GroupElement: Clone + CanonicalKey,
+{
+    let generators = group.generators();
+    let n_generators = generators.len();
+
+    // indices [0, n_generators) are the generators, [n_generators, 2*n_generators) their inverses.
+    let mut signed_generators = Vec::with_capacity(2 * n_generators);
+    signed_generators.extend(generators.iter().cloned());
+    signed_generators.extend(generators.iter().map(|g| g.inv()));
+
+    let inverse_index = |idx: usize| -> usize {
+        if idx < n_generators {
+            idx + n_generators
+        } else {
+            idx - n_generators
+        }
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    let identity = G::GroupElement::identity();
+    seen.insert(identity.canonical_key(numeric_threshold));
+
+    let mut result = vec![identity.clone()];
+    let mut frontier = vec![(identity, None::<usize>)];
+
+    while !frontier.is_empty() && result.len() < max_elements {
+        let mut next_frontier = Vec::new();
+        'frontier: for (word, last_idx) in frontier {
+            for (idx, g) in signed_generators.iter().enumerate() {
+                if last_idx == Some(inverse_index(idx)) {
+                    // skip g·g⁻¹, which would cancel back to the previous word
+                    continue;
+                }
+                let candidate = word.combine(g);
+                if seen.insert(candidate.canonical_key(numeric_threshold)) {
+                    result.push(candidate.clone());
+                    next_frontier.push((candidate, Some(idx)));
+                    if result.len() >= max_elements {
+                        break 'frontier;
+                    }
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    result
+}
+
+impl<Space> Orbit<Space>
+where
+    Space: Sized + PlanarPoint + Clone + PartialEq,
+{
+    /// The 2D convex hull of the orbit's points, e.g. as a first step towards visualizing or
+    /// bounding the limit set of a `FuchsianGroup`.
+    pub fn hull(&self) -> Vec<Space> {
+        convex_hull(&self.points)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SequentialPicker;
+    use super::{NonBacktrackingPicker, SequentialPicker};
+
+    #[test]
+    fn test_non_backtracking_picker_never_follows_a_generator_with_its_inverse() {
+        // indices [0, 1] are the generators, [2, 3] their respective inverses.
+        let group_generators = vec![10, 20, 30, 40];
+        let picker = NonBacktrackingPicker::new(&group_generators, 200, 2);
+
+        let is_inverse_pair = |a: i32, b: i32| {
+            (a, b) == (10, 30) || (a, b) == (30, 10) || (a, b) == (20, 40) || (a, b) == (40, 20)
+        };
+
+        let picks: Vec<i32> = picker.collect();
+        assert_eq!(picks.len(), 200);
+        for pair in picks.windows(2) {
+            assert!(!is_inverse_pair(pair[0], pair[1]), "backtracked via {pair:?}");
+        }
+    }
 
     #[test]
     fn test_sequential_picker() {
@@ -1,10 +1,26 @@
+//! Groundwork for `#![no_std]` support (behind a `std` feature, on by default): `ops` already
+//! routes every transcendental call through either `std` or `libm` (the `libm` feature), and
+//! `geometry`'s curve/distance code only ever goes through `ops` and `alloc`, so with `libm`
+//! enabled none of it depends on `std`'s float precision being available. The crate does not yet
+//! *build* with `std` disabled, though: `algebraic_extensions`, `moebius` and `fuchsian_group`
+//! still reference `std::hash`/`std::result` directly, `fuchsian_group`'s generator
+//! deduplication and `group_action::enumerate_reduced_words` key off `std::collections::HashSet`,
+//! and `PickGeneratorMode::{Random, NonBacktracking}` key off `rand`'s thread-local RNG — these need a follow-up
+//! pass (mostly mechanical `core`/`alloc` swaps, plus a `hashbrown`/`getrandom`-backed
+//! alternative for the two RNG/hashing spots) before `--no-default-features` compiles.
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(dead_code)]
 
+extern crate alloc;
+
 pub(crate) mod algebraic_extensions;
+pub mod disc;
 pub mod fuchsian_group;
 pub mod geometry;
 pub mod group_action;
 pub mod moebius;
+pub(crate) mod ops;
+pub mod proptest_support;
 pub(crate) mod set_extensions;
 
 pub const NUMERIC_THRESHOLD: f64 = 1e-16;
@@ -1,6 +1,8 @@
 use crate::algebraic_extensions::{
-    AddIdentity, Inverse, MulIdentity, Numeric, NumericAddIdentity, NumericMulIdentity,
+    AddIdentity, Inverse, MulIdentity, Numeric, NumericAddIdentity, NumericMulIdentity, SquareRoot,
 };
+use crate::geometry::boundary::BoundaryPoint;
+use num_complex::Complex;
 use std::{
     fmt,
     ops::{Add, Div, Mul, Neg, Sub},
@@ -72,6 +74,186 @@ impl<T> MoebiusTransformation<T> {
         }
         None
     }
+
+    /// The parabolic translation `z -> z + t`, i.e. `[1, t; 0, 1]`.
+    pub fn translation(t: T) -> Self
+    where
+        T: AddIdentity + MulIdentity,
+    {
+        Self::new(T::one(), t, T::zero(), T::one())
+    }
+
+    /// The hyperbolic dilation `z -> s² z`, i.e. `[s, 0; 0, 1/s]`, of determinant `1`.
+    pub fn dilation(s: T) -> Self
+    where
+        T: AddIdentity + MulIdentity + Div<Output = T> + Copy,
+    {
+        Self::new(s, T::zero(), T::zero(), T::one() / s)
+    }
+
+    /// The inversion `z -> -1/z`, i.e. `[0, -1; 1, 0]`.
+    pub fn inversion() -> Self
+    where
+        T: AddIdentity + MulIdentity + Neg<Output = T>,
+    {
+        Self::new(T::zero(), -T::one(), T::one(), T::zero())
+    }
+}
+
+impl MoebiusTransformation<f64> {
+    /// The elliptic rotation by `theta`, i.e. `[cos θ, -sin θ; sin θ, cos θ]`, of determinant `1`.
+    pub fn rotation(theta: f64) -> Self {
+        Self::new(
+            crate::ops::cos(theta),
+            -crate::ops::sin(theta),
+            crate::ops::sin(theta),
+            crate::ops::cos(theta),
+        )
+    }
+}
+
+/// The conjugacy class of a Moebius transformation, determined by its normalized squared trace
+/// `t = (a + d)² / det`: `0 <= t < 4` is `Elliptic` (a rotation around a point of the upper
+/// half-plane), `t ≈ 4` is `Parabolic` (a single boundary fixed point) unless the matrix is
+/// already the identity, and `t > 4` is `Hyperbolic` (two distinct boundary fixed points).
+///
+/// Unlike `fuchsian_group::Classification`, this does not require `determinant == 1` and does
+/// not distinguish the attracting/repelling fixed point of a `Hyperbolic` transformation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformationKind {
+    Identity,
+    Elliptic,
+    Parabolic,
+    Hyperbolic,
+}
+
+/// The fixed point(s) of a Moebius transformation on its boundary: a single point for
+/// `Parabolic` transformations (a double root) or two for `Hyperbolic` ones. `Elliptic`
+/// transformations have no boundary fixed point, see `MoebiusTransformation::fixed_points`.
+pub struct FixedPoints<P> {
+    pub first: P,
+    pub second: Option<P>,
+}
+
+impl<T> MoebiusTransformation<T> {
+    /// Classifies this transformation by its normalized squared trace, see `TransformationKind`.
+    /// Assumes the transformation is invertible, i.e. `determinant() != 0`.
+    pub fn classify(&self, numeric_threshold: Option<f64>) -> TransformationKind
+    where
+        T: Numeric + NumericAddIdentity + MulIdentity + Div<Output = T> + Copy + PartialOrd,
+    {
+        let two = T::one() + T::one();
+        let four = two * two;
+        let trace = self.a + self.d;
+        let t = (trace * trace) / self.determinant();
+
+        if (t + (-four)).is_zero(numeric_threshold) {
+            if self.b.is_zero(numeric_threshold) && self.c.is_zero(numeric_threshold) {
+                TransformationKind::Identity
+            } else {
+                TransformationKind::Parabolic
+            }
+        } else if t < four {
+            TransformationKind::Elliptic
+        } else {
+            TransformationKind::Hyperbolic
+        }
+    }
+
+    /// The fixed point(s) on the boundary, i.e. the roots of `c·z² + (d − a)·z − b = 0`: one
+    /// fixed point is `Infinity` whenever `c ≈ 0`, the other being `b / (d − a)` (or also
+    /// `Infinity`, a double root, when additionally `a ≈ d`). Otherwise the roots are
+    /// `((a − d) ± sqrt((a + d)² − 4·det)) / (2c)`.
+    ///
+    /// For `Elliptic` transformations the discriminant is negative; as elsewhere in this crate
+    /// (see `algebraic_extensions::SquareRoot`), its square root is taken of the absolute value
+    /// rather than promoting to a complex type, so the result is only meaningful for
+    /// `Parabolic`/`Hyperbolic` transformations.
+    pub fn fixed_points(&self, numeric_threshold: Option<f64>) -> FixedPoints<BoundaryPoint<T>>
+    where
+        T: Numeric
+            + NumericAddIdentity
+            + MulIdentity
+            + SquareRoot
+            + Div<Output = T>
+            + Copy
+            + PartialOrd,
+    {
+        if self.c.is_zero(numeric_threshold) {
+            let denom = self.d + (-self.a);
+            return if denom.is_zero(numeric_threshold) {
+                FixedPoints {
+                    first: BoundaryPoint::Infinity,
+                    second: None,
+                }
+            } else {
+                FixedPoints {
+                    first: BoundaryPoint::Infinity,
+                    second: Some(BoundaryPoint::Regular(self.b / denom)),
+                }
+            };
+        }
+
+        let two = T::one() + T::one();
+        let four = two * two;
+        let trace = self.a + self.d;
+        let discriminant = trace * trace + (-(four * self.determinant()));
+        let two_c = self.c + self.c;
+        let base = self.a + (-self.d);
+
+        if discriminant.is_zero(numeric_threshold) {
+            FixedPoints {
+                first: BoundaryPoint::Regular(base / two_c),
+                second: None,
+            }
+        } else {
+            let sqrt_disc = discriminant.square_root();
+            FixedPoints {
+                first: BoundaryPoint::Regular((base + sqrt_disc) / two_c),
+                second: Some(BoundaryPoint::Regular((base + (-sqrt_disc)) / two_c)),
+            }
+        }
+    }
+}
+
+impl<T> MoebiusTransformation<Complex<T>> {
+    /// The unique Moebius transformation sending `z1, z2, z3 -> 0, 1, ∞`, given by
+    /// `M(z) = ((z − z1)(z2 − z3)) / ((z − z3)(z2 − z1))`.
+    pub fn from_three_points(z1: Complex<T>, z2: Complex<T>, z3: Complex<T>) -> Self
+    where
+        Complex<T>: Sub<Output = Complex<T>> + Mul<Output = Complex<T>> + Neg<Output = Complex<T>> + Clone,
+    {
+        let a = z2.clone() - z3.clone();
+        let c = z2 - z1.clone();
+        let b = -(z1 * a.clone());
+        let d = -(z3 * c.clone());
+        Self::new(a, b, c, d)
+    }
+
+    /// The Moebius transformation mapping `src[0], src[1], src[2]` to `dst[0], dst[1], dst[2]`
+    /// respectively, obtained as `from_three_points(dst)⁻¹ ∘ from_three_points(src)`.
+    pub fn mapping_three_points(src: [Complex<T>; 3], dst: [Complex<T>; 3]) -> Option<Self>
+    where
+        Complex<T>: Numeric + NumericAddIdentity + Copy + Div<Output = Complex<T>>,
+    {
+        let [s1, s2, s3] = src;
+        let [d1, d2, d3] = dst;
+        let m_src = Self::from_three_points(s1, s2, s3);
+        let m_dst = Self::from_three_points(d1, d2, d3);
+        let m_dst_inv = m_dst.inverse(None)?;
+        Some(m_dst_inv * m_src)
+    }
+}
+
+/// The cross-ratio `((z − z1)(z2 − z3)) / ((z − z3)(z2 − z1))`, invariant under Moebius
+/// transformations.
+pub fn cross_ratio<T>(z: Complex<T>, z1: Complex<T>, z2: Complex<T>, z3: Complex<T>) -> Complex<T>
+where
+    Complex<T>: Sub<Output = Complex<T>> + Mul<Output = Complex<T>> + Div<Output = Complex<T>> + Clone,
+{
+    let numerator = (z.clone() - z1.clone()) * (z2.clone() - z3.clone());
+    let denominator = (z - z3) * (z2 - z1);
+    numerator / denominator
 }
 
 // ########################
@@ -266,8 +448,10 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::MoebiusTransformation;
+    use super::{cross_ratio, FixedPoints, MoebiusTransformation, TransformationKind};
     use crate::algebraic_extensions::{AddIdentity, MulIdentity, NumericMulIdentity};
+    use crate::geometry::boundary::BoundaryPoint;
+    use num_complex::Complex;
 
     #[test]
     fn test_macro() {
@@ -407,4 +591,109 @@ mod tests {
         assert!(numerical_one.is_one(Some(1e-7)));
         assert!(!numerical_one.is_one(Some(1e-8)));
     }
+
+    #[test]
+    fn test_classify_identity() {
+        let m = MoebiusTransformation::<f64>::one();
+        assert_eq!(m.classify(None), TransformationKind::Identity);
+    }
+
+    #[test]
+    fn test_classify_and_fixed_points_parabolic() {
+        // horocyclic: z -> z + 10
+        let m = MoebiusTransformation::<f64>::new(1.0, 10.0, 0.0, 1.0);
+        assert_eq!(m.classify(None), TransformationKind::Parabolic);
+
+        match m.fixed_points(None) {
+            FixedPoints {
+                first: BoundaryPoint::Infinity,
+                second: None,
+            } => {}
+            _ => panic!("expected a single fixed point at infinity"),
+        }
+    }
+
+    #[test]
+    fn test_classify_and_fixed_points_hyperbolic() {
+        let m = MoebiusTransformation::<f64>::new(5.0, 0.0, 0.0, 0.2);
+        assert_eq!(m.classify(None), TransformationKind::Hyperbolic);
+
+        match m.fixed_points(None) {
+            FixedPoints {
+                first: BoundaryPoint::Infinity,
+                second: Some(BoundaryPoint::Regular(z)),
+            } => assert_eq!(z, 0.0),
+            _ => panic!("expected fixed points at infinity and 0"),
+        }
+    }
+
+    #[test]
+    fn test_classify_and_fixed_points_hyperbolic_shear() {
+        let m = MoebiusTransformation::<f64>::new(2.0, 3.0, 0.0, 0.5);
+        assert_eq!(m.classify(None), TransformationKind::Hyperbolic);
+
+        match m.fixed_points(None) {
+            FixedPoints {
+                first: BoundaryPoint::Infinity,
+                second: Some(BoundaryPoint::Regular(z)),
+            } => assert_eq!(z, -2.0),
+            _ => panic!("expected fixed points at infinity and -2"),
+        }
+    }
+
+    #[test]
+    fn test_classify_rotation() {
+        // rotation by pi/2: [0, -1; 1, 0]
+        let m = MoebiusTransformation::<f64>::new(0.0, -1.0, 1.0, 0.0);
+        assert_eq!(m.classify(None), TransformationKind::Elliptic);
+    }
+
+    #[test]
+    fn test_named_generators() {
+        let translation = MoebiusTransformation::<f64>::translation(3.0);
+        assert_eq!(translation, MoebiusTransformation::new(1.0, 3.0, 0.0, 1.0));
+        assert_eq!(translation.determinant(), 1.0);
+
+        let dilation = MoebiusTransformation::<f64>::dilation(2.0);
+        assert_eq!(dilation, MoebiusTransformation::new(2.0, 0.0, 0.0, 0.5));
+        assert_eq!(dilation.determinant(), 1.0);
+
+        let inversion = MoebiusTransformation::<f64>::inversion();
+        assert_eq!(inversion, MoebiusTransformation::new(0.0, -1.0, 1.0, 0.0));
+        assert_eq!(inversion.determinant(), 1.0);
+
+        let rotation = MoebiusTransformation::rotation(std::f64::consts::FRAC_PI_2);
+        assert!((rotation.determinant() - 1.0).abs() < 1e-12);
+        assert!((rotation.a - 0.0).abs() < 1e-12);
+        assert!((rotation.b - (-1.0)).abs() < 1e-12);
+        assert!((rotation.c - 1.0).abs() < 1e-12);
+        assert!((rotation.d - 0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cross_ratio_of_the_defining_points() {
+        let z1 = Complex::new(1.0, 0.0);
+        let z2 = Complex::new(0.0, 1.0);
+        let z3 = Complex::new(-1.0, 0.0);
+
+        assert_eq!(cross_ratio(z1, z1, z2, z3), Complex::new(0.0, 0.0));
+        assert_eq!(cross_ratio(z2, z1, z2, z3), Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_from_three_points_maps_to_zero_one_infinity() {
+        let z1 = Complex::new(1.0, 0.0);
+        let z2 = Complex::new(0.0, 1.0);
+        let z3 = Complex::new(-1.0, 0.0);
+
+        let m = MoebiusTransformation::from_three_points(z1, z2, z3);
+
+        let image_of_z1 = (m.a * z1 + m.b) / (m.c * z1 + m.d);
+        let image_of_z2 = (m.a * z2 + m.b) / (m.c * z2 + m.d);
+
+        assert_eq!(image_of_z1, Complex::new(0.0, 0.0));
+        assert_eq!(image_of_z2, Complex::new(1.0, 0.0));
+        // z3 is sent to infinity: the denominator vanishes there
+        assert_eq!(m.c * z3 + m.d, Complex::new(0.0, 0.0));
+    }
 }
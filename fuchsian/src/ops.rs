@@ -0,0 +1,62 @@
+//! Deterministic, cross-platform float primitives used by `Distance` and `Drawable2d`.
+//!
+//! `sqrt`/`ln`/`sin`/`cos` on `f32`/`f64` have unspecified precision, so hyperbolic distances and
+//! sampled curves can differ bit-for-bit across platforms and Rust versions. This module
+//! re-exports either the std methods (default) or the `libm` equivalents (behind the `libm`
+//! feature, e.g. for reproducible output on WASM) under a single name, plus a `FloatPow` helper
+//! for `squared`/`cubed` since `libm` has no `powi`.
+
+pub(crate) trait FloatPow: Sized + Copy {
+    fn squared(self) -> Self;
+    fn cubed(self) -> Self;
+}
+
+impl FloatPow for f64 {
+    fn squared(self) -> Self {
+        self * self
+    }
+
+    fn cubed(self) -> Self {
+        self * self * self
+    }
+}
+
+#[cfg(not(feature = "libm"))]
+mod backend {
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub(crate) fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    pub(crate) fn sin(x: f64) -> f64 {
+        x.sin()
+    }
+
+    pub(crate) fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+}
+
+#[cfg(feature = "libm")]
+mod backend {
+    pub(crate) fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    pub(crate) fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+
+    pub(crate) fn sin(x: f64) -> f64 {
+        libm::sin(x)
+    }
+
+    pub(crate) fn cos(x: f64) -> f64 {
+        libm::cos(x)
+    }
+}
+
+pub(crate) use backend::{cos, ln, sin, sqrt};
@@ -0,0 +1,95 @@
+//! Optional `proptest` strategies for `MoebiusTransformation<f64>`, gated behind the `proptest`
+//! feature. Exported (not just used internally) so downstream crates can fuzz their own
+//! Fuchsian-group code against this type instead of hand-rolling generators.
+#![cfg(feature = "proptest")]
+
+use crate::moebius::MoebiusTransformation;
+use proptest::prelude::*;
+
+/// A strategy generating `MoebiusTransformation<f64>` with entries in `[-10, 10]`, with no
+/// guarantee of invertibility.
+pub fn arb_moebius() -> impl Strategy<Item = MoebiusTransformation<f64>> {
+    (
+        -10.0..10.0f64,
+        -10.0..10.0f64,
+        -10.0..10.0f64,
+        -10.0..10.0f64,
+    )
+        .prop_map(|(a, b, c, d)| MoebiusTransformation::new(a, b, c, d))
+}
+
+/// A strategy generating invertible `MoebiusTransformation<f64>`, i.e. with `|determinant()|`
+/// bounded away from zero.
+pub fn arb_invertible_moebius() -> impl Strategy<Item = MoebiusTransformation<f64>> {
+    arb_moebius().prop_filter("determinant must be nonzero", |m| m.determinant().abs() > 1e-6)
+}
+
+/// A strategy generating determinant-one (`SpecialLinear`) `MoebiusTransformation<f64>`, by
+/// rescaling an invertible sample and, if needed, negating its second row to flip the sign of
+/// the determinant.
+pub fn arb_special_linear_moebius() -> impl Strategy<Item = MoebiusTransformation<f64>> {
+    arb_invertible_moebius().prop_map(|m| {
+        let scale = 1.0 / m.determinant().abs().sqrt();
+        let scaled = m * scale;
+        if scaled.determinant() < 0.0 {
+            MoebiusTransformation::new(scaled.a, scaled.b, -scaled.c, -scaled.d)
+        } else {
+            scaled
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{arb_invertible_moebius, arb_moebius, arb_special_linear_moebius};
+    use crate::algebraic_extensions::{NumericAddIdentity, NumericMulIdentity};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_addition_is_associative(m1 in arb_moebius(), m2 in arb_moebius(), m3 in arb_moebius()) {
+            // f64 addition is only associative up to rounding
+            prop_assert!(((m1 + m2) + m3 - (m1 + (m2 + m3))).is_zero(Some(1e-9)));
+        }
+
+        #[test]
+        fn test_multiplication_distributes_over_addition(m1 in arb_moebius(), m2 in arb_moebius(), m3 in arb_moebius()) {
+            // f64 multiplication only distributes over addition up to rounding
+            prop_assert!((m1 * (m2 + m3) - (m1 * m2 + m1 * m3)).is_zero(Some(1e-9)));
+        }
+
+        #[test]
+        fn test_determinant_is_multiplicative(m1 in arb_moebius(), m2 in arb_moebius()) {
+            let lhs = (m1 * m2).determinant();
+            let rhs = m1.determinant() * m2.determinant();
+            prop_assert!((lhs - rhs).abs() < 1e-6 * (1.0 + rhs.abs()));
+        }
+
+        #[test]
+        fn test_inverse_composes_to_identity(m in arb_invertible_moebius()) {
+            let inv = m.inverse(None).expect("sampled as invertible");
+            prop_assert!((m * inv).is_one(Some(1e-6)));
+            prop_assert!((inv * m).is_one(Some(1e-6)));
+        }
+
+        #[test]
+        fn test_special_linear_composition_stays_determinant_one(
+            m1 in arb_special_linear_moebius(),
+            m2 in arb_special_linear_moebius(),
+        ) {
+            prop_assert!(((m1 * m2).determinant() - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_arb_special_linear_moebius_is_determinant_one() {
+        let mut runner = proptest::test_runner::TestRunner::default();
+        for _ in 0..64 {
+            let m = arb_special_linear_moebius()
+                .new_tree(&mut runner)
+                .unwrap()
+                .current();
+            assert!((m.determinant() - 1.0).abs() < 1e-6);
+        }
+    }
+}
@@ -2,6 +2,8 @@ use fuchsian::geometry::basics::Drawable2d;
 use fuchsian::geometry::boundary::BoundaryPoint;
 use fuchsian::geometry::geodesics::{GeodesicBoundary, GeodesicLine};
 use fuchsian::geometry::horocycle::GeometricHorocCycle;
+use fuchsian::geometry::svg::{horocycle_orbit_to_svg_arcs, orbit_to_svg_arcs, ColorRamp, SvgConfig};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 extern crate fuchsian;
 use fuchsian::group_action::{Orbit, PickGeneratorMode};
@@ -42,15 +44,17 @@ fn plot_geodesic(geodesic_boundary: GeodesicBoundary<f64>, n_curve_pts: usize) -
     line.draw(n_curve_pts)
 }
 
-fn parse_generator_mode(mode: Option<String>) -> Option<PickGeneratorMode> {
-    if let Some(m) = mode {
-        match m.to_lowercase().trim() {
-            "random" => Some(PickGeneratorMode::Random),
-            "sequential" => Some(PickGeneratorMode::Sequential),
-            _ => todo!(), // return Err(PyErr::new("Invalid mode")),
-        }
-    } else {
-        None
+fn parse_generator_mode(mode: Option<String>) -> PyResult<Option<PickGeneratorMode>> {
+    let Some(m) = mode else {
+        return Ok(None);
+    };
+    match m.to_lowercase().trim() {
+        "random" => Ok(Some(PickGeneratorMode::Random)),
+        "sequential" => Ok(Some(PickGeneratorMode::Sequential)),
+        "nonbacktracking" => Ok(Some(PickGeneratorMode::NonBacktracking)),
+        _ => Err(PyValueError::new_err(
+            "mode must be \"random\", \"sequential\" or \"nonbacktracking\"",
+        )),
     }
 }
 
@@ -64,7 +68,7 @@ fn geodesic_orbit(
 ) -> PyResult<Vec<Vec<(f64, f64)>>> {
     let m = moebius.into_iter().map(parse_moebius).collect();
     let base_geodesic = parse_geodesic_boundary(end_points);
-    let pick_mode = parse_generator_mode(mode);
+    let pick_mode = parse_generator_mode(mode)?;
 
     let fuchsian_group = FuchsianGroup::create_projected(m, None);
     let orbit = Orbit::sample(&fuchsian_group, &base_geodesic, n_pts, pick_mode);
@@ -88,7 +92,7 @@ fn horocyclic_orbit(
 ) -> PyResult<Vec<Vec<(f64, f64)>>> {
     let m = moebius.into_iter().map(parse_moebius).collect();
     let base_horocycle = parse_horocycle(euclidean_height);
-    let pick_mode = parse_generator_mode(mode);
+    let pick_mode = parse_generator_mode(mode)?;
 
     let fuchsian_group = FuchsianGroup::create_projected(m, None);
     let orbit = Orbit::sample(&fuchsian_group, &base_horocycle, n_pts, pick_mode);
@@ -111,7 +115,7 @@ fn orbit(
 ) -> PyResult<Vec<(f64, f64)>> {
     let m = moebius.into_iter().map(parse_moebius).collect();
     let base_point = parse_complex(base_point);
-    let pick_mode = parse_generator_mode(mode);
+    let pick_mode = parse_generator_mode(mode)?;
 
     let fuchsian_group = FuchsianGroup::create_projected(m, None);
     let orbit = Orbit::sample(&fuchsian_group, &base_point, n_pts, pick_mode);
@@ -121,6 +125,46 @@ fn orbit(
     Ok(orbit_ves)
 }
 
+/// Renders an `Orbit<GeodesicLine<f64>>` (`kind == "geodesic"`) or an
+/// `Orbit<GeometricHorocCycle<f64>>` (`kind == "horocycle"`) as a standalone SVG document using
+/// elliptical-arc path commands, with an optional cyclic `colors` ramp applied by orbit index.
+#[pyfunction]
+fn orbit_svg(
+    moebius: Vec<((f64, f64), (f64, f64))>,
+    kind: String,
+    end_points: (f64, f64),
+    n_pts: usize,
+    mode: Option<String>,
+    colors: Option<Vec<String>>,
+) -> PyResult<String> {
+    let m = moebius.into_iter().map(parse_moebius).collect();
+    let pick_mode = parse_generator_mode(mode)?;
+    let fuchsian_group = FuchsianGroup::create_projected(m, None);
+    let config = SvgConfig::default();
+    let ramp = colors
+        .map(|colors| ColorRamp { colors })
+        .unwrap_or_default();
+
+    match kind.to_lowercase().trim() {
+        "geodesic" => {
+            let base_geodesic = parse_geodesic_boundary(end_points);
+            let orbit = Orbit::sample(&fuchsian_group, &base_geodesic, n_pts, pick_mode);
+            let line_orbit = Orbit {
+                points: orbit.points.into_iter().map(GeodesicLine::from).collect(),
+            };
+            Ok(orbit_to_svg_arcs(&line_orbit, &config, &ramp))
+        }
+        "horocycle" => {
+            let base_horocycle = parse_horocycle(end_points.0);
+            let orbit = Orbit::sample(&fuchsian_group, &base_horocycle, n_pts, pick_mode);
+            Ok(horocycle_orbit_to_svg_arcs(&orbit, &config, &ramp))
+        }
+        _ => Err(PyValueError::new_err(
+            "kind must be \"geodesic\" or \"horocycle\"",
+        )),
+    }
+}
+
 /// A Python module implemented in Rust.
 #[pymodule]
 fn python_api(_py: Python, m: &PyModule) -> PyResult<()> {
@@ -128,5 +172,6 @@ fn python_api(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(orbit, m)?)?;
     m.add_function(wrap_pyfunction!(geodesic_orbit, m)?)?;
     m.add_function(wrap_pyfunction!(horocyclic_orbit, m)?)?;
+    m.add_function(wrap_pyfunction!(orbit_svg, m)?)?;
     Ok(())
 }